@@ -0,0 +1,58 @@
+//! Benchmarks of the parsing and both parts of every day against the checked-in inputs.
+//!
+//! The days taking minutes to solve are skipped unless a filter selects them, e.g. `cargo bench -- 2023/day05`.
+
+use std::env;
+use std::hint::black_box;
+use std::path::Path;
+
+use advent_of_code_2023::timing::Phase;
+use advent_of_code_2023::{input, Day, Part};
+
+use criterion::{criterion_group, criterion_main, Criterion, SamplingMode};
+use strum::IntoEnumIterator;
+
+/// Returns whether a filter given on the command line selects `day`, either naming it or one of its benchmarks.
+fn is_selected(day: Day) -> bool {
+    let name = day.to_string();
+
+    env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .any(|filter| name.contains(&filter) || filter.contains(&name))
+}
+
+fn bench_days(c: &mut Criterion) {
+    for day in Day::iter() {
+        if let Some(reason) = day.slowness().filter(|_| !is_selected(day)) {
+            eprintln!("skipping {day}: {reason}, select it with `cargo bench -- {day}`");
+            continue;
+        }
+
+        let path = input::get_input_path(day, Path::new(input::DEFAULT_DATA_DIR));
+        let Ok(input) = input::read_input(&path) else {
+            eprintln!("skipping {day}: cannot read input '{}'", path.display());
+            continue;
+        };
+        let model = advent_of_code_2023::parse(day, &input).expect("checked-in input must be well-formed");
+
+        let mut group = c.benchmark_group(day.to_string());
+
+        group.sampling_mode(SamplingMode::Flat).sample_size(10);
+
+        group.bench_function(Phase::Parse.to_string(), |b| {
+            b.iter(|| advent_of_code_2023::parse(day, black_box(&input)))
+        });
+
+        for part in Part::iter() {
+            group.bench_function(Phase::Solve(part).to_string(), |b| {
+                b.iter(|| advent_of_code_2023::solve(day, part, black_box(&model)))
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,109 @@
+//! Lists the example inputs found in `examples/{year}/day{nn}/` for `tests/examples.rs` to generate a test per file,
+//! and the days found in `src/years/y{year}/day{nn}.rs` for `tests/answers.rs` to generate a test per day.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+const EXAMPLES_DIR: &str = "examples";
+const YEARS_DIR: &str = "src/years";
+const SLOW_DAYS_PATH: &str = "src/slow.rs";
+
+include!("src/slow.rs");
+
+/// Returns the entries of `dir` in order, along with their name.
+fn entries(dir: &Path) -> Vec<(String, PathBuf)> {
+    let mut entries = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| (entry.file_name().to_string_lossy().into_owned(), entry.path()))
+        .collect::<Vec<_>>();
+
+    entries.sort();
+    entries
+}
+
+/// Turns the file stem `stem` into a valid identifier.
+fn identifier(stem: &str) -> String {
+    stem.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Returns the `examples!` invocation generating a test per example input.
+fn examples() -> String {
+    let mut tests = String::new();
+
+    for (year, year_dir) in entries(Path::new(EXAMPLES_DIR)) {
+        let Ok(year) = year.parse::<u16>() else {
+            continue;
+        };
+
+        for (day, day_dir) in entries(&year_dir) {
+            let Some(day) = day.strip_prefix("day").and_then(|day| day.parse::<u8>().ok()) else {
+                continue;
+            };
+
+            for (name, _) in entries(&day_dir) {
+                let Some(stem) = name.strip_suffix(".txt") else {
+                    continue;
+                };
+
+                tests.push_str(&format!(
+                    "    it_solves_{year}_day{day:02}_{}: {year} / {day} / {:?},\n",
+                    identifier(stem),
+                    format!("{year}/day{day:02}/{name}")
+                ));
+            }
+        }
+    }
+
+    format!("examples! {{\n{tests}}}\n")
+}
+
+/// Returns the `verify!` invocation generating a test per day module.
+fn answers() -> String {
+    let mut tests = String::new();
+
+    for (year, year_dir) in entries(Path::new(YEARS_DIR)) {
+        let Some(year) = year.strip_prefix('y').and_then(|year| year.parse::<u16>().ok()) else {
+            continue;
+        };
+
+        for (day, _) in entries(&year_dir) {
+            let Some(day) = day
+                .strip_prefix("day")
+                .and_then(|day| day.strip_suffix(".rs"))
+                .and_then(|day| day.parse::<u8>().ok())
+            else {
+                continue;
+            };
+
+            if let Some((_, _, reason)) = SLOW_DAYS.iter().find(|(y, d, _)| (*y, *d) == (year, day)) {
+                tests.push_str(&format!("    #[ignore = {reason:?}]\n"));
+            }
+
+            tests.push_str(&format!("    it_solves_{year}_day{day:02}: {year} / {day},\n"));
+        }
+    }
+
+    format!("verify! {{\n{tests}}}\n")
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={EXAMPLES_DIR}");
+    println!("cargo:rerun-if-changed={YEARS_DIR}");
+    println!("cargo:rerun-if-changed={SLOW_DAYS_PATH}");
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("cargo sets OUT_DIR"));
+
+    fs::write(out_dir.join("examples.rs"), examples()).expect("OUT_DIR is writable");
+    fs::write(out_dir.join("answers.rs"), answers()).expect("OUT_DIR is writable");
+}
@@ -0,0 +1,248 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, LitStr};
+
+/// Piece of the pattern of a struct.
+#[derive(Debug, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Field { name: String, separator: Option<char> },
+}
+
+/// Splits `pattern` into literals and `{field}` or `{field:separator}` placeholders, `{_}` standing for ignored text
+/// and `{{` and `}}` escaping braces.
+fn parse_pattern(pattern: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = Vec::default();
+    let mut literal = String::new();
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let placeholder = chars.by_ref().take_while(|c| *c != '}').collect::<String>();
+                let (name, separator) = match placeholder.split_once(':') {
+                    Some((name, separator)) => {
+                        let mut separator_chars = separator.chars();
+
+                        match (separator_chars.next(), separator_chars.next()) {
+                            (Some(separator), None) => (name, Some(separator)),
+                            _ => return Err(format!("separator of `{name}` must be a single character")),
+                        }
+                    }
+                    None => (placeholder.as_str(), None),
+                };
+
+                if name.is_empty() {
+                    return Err("placeholders must name a field".to_owned());
+                }
+
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+
+                segments.push(Segment::Field {
+                    name: name.trim().to_owned(),
+                    separator,
+                });
+            }
+            '}' => return Err("unmatched `}`, use `}}` for a literal one".to_owned()),
+            c => literal.push(c),
+        }
+    }
+
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    Ok(segments)
+}
+
+fn expand_struct(item: &DeriveInput, fields: &Fields) -> syn::Result<TokenStream> {
+    let attr = item
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("input"))
+        .ok_or_else(|| Error::new_spanned(&item.ident, "expected an `#[input(\"...\")]` pattern"))?;
+    let pattern = attr.parse_args::<LitStr>()?;
+    let segments = parse_pattern(&pattern.value()).map_err(|message| Error::new_spanned(&pattern, message))?;
+
+    let names = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| field.ident.as_ref().map_or_else(|| i.to_string(), ToString::to_string))
+        .collect::<Vec<_>>();
+    let placeholders = segments
+        .iter()
+        .filter_map(|segment| {
+            match segment {
+                Segment::Field { name, .. } if name != "_" => Some(name.as_str()),
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for name in &names {
+        match placeholders.iter().filter(|placeholder| *placeholder == name).count() {
+            1 => {}
+            0 => {
+                return Err(Error::new_spanned(
+                    &pattern,
+                    format!("missing a placeholder for `{name}`"),
+                ))
+            }
+            _ => return Err(Error::new_spanned(&pattern, format!("`{name}` appears more than once"))),
+        }
+    }
+
+    if let Some(placeholder) = placeholders
+        .iter()
+        .find(|placeholder| !names.iter().any(|name| name == *placeholder))
+    {
+        return Err(Error::new_spanned(&pattern, format!("no field named `{placeholder}`")));
+    }
+
+    let mut statements = Vec::default();
+
+    for (i, segment) in segments.iter().enumerate() {
+        match segment {
+            // literals following a field delimit it and are consumed along with it
+            Segment::Literal(literal) if i == 0 => {
+                statements.extend(literal.split_whitespace().map(|word| quote!(cursor.tag(#word)?;)));
+            }
+            Segment::Literal(_) => {}
+            Segment::Field { name, separator } => {
+                let var = format_ident!("field_{}", name);
+                let field = match segments.get(i + 1) {
+                    Some(Segment::Literal(literal)) if literal.trim().is_empty() => quote!(cursor.token()?),
+                    Some(Segment::Literal(literal)) => {
+                        let delimiter = literal.trim();
+
+                        quote!(cursor.until(#delimiter)?)
+                    }
+                    Some(Segment::Field { .. }) if separator.is_some() || name == "_" => {
+                        return Err(Error::new_spanned(
+                            &pattern,
+                            format!("`{{{name}}}` must be followed by a literal"),
+                        ));
+                    }
+                    Some(Segment::Field { .. }) => {
+                        statements.push(quote!(let #var = crate::parser::FromInput::read(cursor)?;));
+                        continue;
+                    }
+                    None => quote!(cursor.take()),
+                };
+
+                statements.push(match separator {
+                    _ if name == "_" => quote!(#field;),
+                    Some(separator) => {
+                        quote! {
+                            let #var = #field
+                                .split(#separator)
+                                .map(crate::parser::FromInput::from_line)
+                                .collect::<Result<_, _>>()?;
+                        }
+                    }
+                    None => {
+                        quote! {
+                            let #var = crate::parser::FromInput::from_line(#field)?;
+                        }
+                    }
+                });
+            }
+        }
+    }
+
+    let constructor = match fields {
+        Fields::Named(_) => {
+            let idents = fields.iter().map(|field| &field.ident);
+            let vars = names.iter().map(|name| format_ident!("field_{}", name));
+
+            quote!(Self { #(#idents: #vars),* })
+        }
+        Fields::Unnamed(_) => {
+            let vars = names.iter().map(|name| format_ident!("field_{}", name));
+
+            quote!(Self(#(#vars),*))
+        }
+        Fields::Unit => quote!(Self),
+    };
+
+    Ok(quote! {
+        #(#statements)*
+
+        Ok(#constructor)
+    })
+}
+
+fn expand_enum(item: &DeriveInput) -> TokenStream {
+    let expected = item.ident.to_string().to_lowercase();
+
+    quote!(cursor.parse::<Self>(#expected))
+}
+
+pub fn expand(item: DeriveInput) -> syn::Result<TokenStream> {
+    let body = match &item.data {
+        Data::Struct(data) => expand_struct(&item, &data.fields)?,
+        Data::Enum(data) if data.variants.iter().all(|variant| variant.fields.is_empty()) => expand_enum(&item),
+        Data::Enum(_) => return Err(Error::new_spanned(&item.ident, "only fieldless enums can be derived")),
+        Data::Union(_) => return Err(Error::new(Span::call_site(), "unions cannot be derived")),
+    };
+    let ident = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics crate::parser::FromInput for #ident #ty_generics #where_clause {
+            fn read(cursor: &mut crate::parser::Cursor) -> Result<Self, crate::parser::ParseError> {
+                #body
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, separator: Option<char>) -> Segment {
+        Segment::Field {
+            name: name.to_owned(),
+            separator,
+        }
+    }
+
+    #[test]
+    fn it_parses_patterns() {
+        assert_eq!(
+            Ok(vec![
+                Segment::Literal("Game ".to_owned()),
+                field("id", None),
+                Segment::Literal(": ".to_owned()),
+                field("sets", Some(';')),
+            ]),
+            parse_pattern("Game {id}: {sets:;}")
+        );
+        assert_eq!(
+            Ok(vec![
+                Segment::Literal("{".to_owned()),
+                field("0", None),
+                Segment::Literal("}".to_owned())
+            ]),
+            parse_pattern("{{{0}}}")
+        );
+    }
+
+    #[test]
+    fn it_rejects_malformed_patterns() {
+        assert!(parse_pattern("{}").is_err());
+        assert!(parse_pattern("{sets:;;}").is_err());
+        assert!(parse_pattern("}").is_err());
+    }
+}
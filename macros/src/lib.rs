@@ -0,0 +1,128 @@
+//! Attribute registering the parser and solvers of the days of `advent-of-code-2023`, and derive reading their models
+//! from the input.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::{parse_macro_input, DeriveInput, Error, ItemFn, LitInt, LitStr};
+
+mod from_input;
+
+#[derive(Default)]
+struct Args {
+    year: Option<LitInt>,
+    day: Option<LitInt>,
+    part: Option<LitInt>,
+    variant: Option<LitStr>,
+}
+
+impl Args {
+    fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("year") {
+            self.year = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("day") {
+            self.day = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("part") {
+            self.part = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("variant") {
+            self.variant = Some(meta.value()?.parse()?);
+        } else {
+            return Err(meta.error("expected `year`, `day`, `part` or `variant`"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Registers the annotated function as the input parser of a day, `#[aoc(year = 2023, day = 8)]`, or as the solver of
+/// one of its parts, `#[aoc(year = 2023, day = 8, part = 1)]`, optionally labelled as an alternative one with
+/// `variant = "..."`.
+///
+/// Parsers take the puzzle input and return `Result<T, ParseError>`, solvers take `&T`, or `&[U]` and `&str` for
+/// `Vec<U>` and `String` models, and return anything convertible into an `Answer`.
+#[proc_macro_attribute]
+pub fn aoc(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut args = Args::default();
+    let parser = syn::meta::parser(|meta| args.parse(meta));
+
+    parse_macro_input!(attr with parser);
+
+    let item = parse_macro_input!(item as ItemFn);
+
+    expand(args, item).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand(args: Args, item: ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &item.sig.ident;
+    let name = ident.to_string();
+    let year = args
+        .year
+        .ok_or_else(|| Error::new_spanned(&item.sig, "expected a `year` argument"))?;
+    let day = args
+        .day
+        .ok_or_else(|| Error::new_spanned(&item.sig, "expected a `day` argument"))?;
+
+    // the first event took place in 2015
+    if year.base10_parse::<u16>().map_or(true, |year| year < 2015) {
+        return Err(Error::new_spanned(year, "expected a year from 2015"));
+    }
+
+    if day.base10_parse::<u8>().map_or(true, |day| !(1..=25).contains(&day)) {
+        return Err(Error::new_spanned(day, "expected a day between 1 and 25"));
+    }
+
+    let registration = match (args.part, args.variant) {
+        (None, None) => {
+            quote! {
+                crate::registry::Parser {
+                    year: #year,
+                    day: #day,
+                    name: #name,
+                    parse: |input| #ident(input).map(crate::Model::new),
+                }
+            }
+        }
+        (None, Some(variant)) => return Err(Error::new_spanned(variant, "only solvers have variants")),
+        (Some(part), variant) => {
+            let part = match part.base10_parse::<u8>()? {
+                1 => quote!(crate::Part::One),
+                2 => quote!(crate::Part::Two),
+                _ => return Err(Error::new_spanned(part, "expected part 1 or 2")),
+            };
+            let variant = match variant {
+                Some(variant) => quote!(Some(#variant)),
+                None => quote!(None),
+            };
+
+            quote! {
+                crate::registry::Solution {
+                    year: #year,
+                    day: #day,
+                    part: #part,
+                    name: #name,
+                    variant: #variant,
+                    solve: |model| #ident(crate::registry::FromModel::from_model(model)).into(),
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        #item
+
+        ::inventory::submit! {
+            #registration
+        }
+    })
+}
+
+/// Derives `FromInput` for a struct from the pattern given by its `#[input("...")]` attribute, or for a fieldless enum
+/// implementing `FromStr`.
+#[proc_macro_derive(FromInput, attributes(input))]
+pub fn derive_from_input(item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as DeriveInput);
+
+    from_input::expand(item)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,68 @@
+use std::fmt;
+
+/// Answer of a puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(i128),
+    String(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(v) => v.fmt(f),
+            Self::BigInteger(v) => v.fmt(f),
+            Self::String(v) => v.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(v: $t) -> Self {
+                    match i64::try_from(v) {
+                        Ok(v) => Self::Integer(v),
+                        Err(_) => Self::BigInteger(v as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(v: String) -> Self {
+        Self::String(v)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(v: &str) -> Self {
+        Self::String(v.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_converts_integers_to_the_narrowest_variant() {
+        assert_eq!(Answer::Integer(42), 42usize.into());
+        assert_eq!(Answer::Integer(-42), (-42i32).into());
+        assert_eq!(Answer::BigInteger(u64::MAX as i128), u64::MAX.into());
+        assert_eq!(Answer::BigInteger(i128::MIN), i128::MIN.into());
+    }
+
+    #[test]
+    fn it_displays_the_inner_value() {
+        assert_eq!("42", Answer::Integer(42).to_string());
+        assert_eq!("18446744073709551615", Answer::BigInteger(u64::MAX as i128).to_string());
+        assert_eq!("EZRA", Answer::from("EZRA").to_string());
+    }
+}
@@ -0,0 +1,277 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::{fmt, fs, io};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::runner::Record;
+use crate::timing::Phase;
+use crate::{Answer, Day, Part};
+
+/// Answers file checked in the repository.
+pub const DEFAULT_ANSWERS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Toml(#[from] toml::de::Error),
+    #[error(transparent)]
+    TomlSer(#[from] toml::ser::Error),
+}
+
+/// Expected answer of a part, either in plain text or as a salted digest not to disclose it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Plain(String),
+    /// Hex-encoded SHA-256 digest of the salt followed by the answer.
+    Digest {
+        salt: String,
+        sha256: String,
+    },
+}
+
+impl Expected {
+    /// Digests `answer` with a random salt.
+    pub fn digest(answer: &Answer) -> Self {
+        let salt = to_hex(&rand::random::<[u8; 16]>());
+
+        Self::Digest {
+            sha256: digest(&salt, answer),
+            salt,
+        }
+    }
+
+    pub fn matches(&self, answer: &Answer) -> bool {
+        match self {
+            Self::Plain(expected) => *expected == answer.to_string(),
+            Self::Digest { salt, sha256 } => *sha256 == digest(salt, answer),
+        }
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Plain(expected) => expected.fmt(f),
+            Self::Digest { sha256, .. } => write!(f, "sha256:{}", &sha256[..sha256.len().min(8)]),
+        }
+    }
+}
+
+fn digest(salt: &str, answer: &Answer) -> String {
+    let mut hasher = Sha256::new();
+
+    hasher.update(salt);
+    hasher.update(answer.to_string());
+
+    to_hex(&hasher.finalize())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Expected answers of each part, keyed by year, day then part, e.g. `[2023.day01]` then `part1 = "142"` or
+/// `part1 = { salt = "...", sha256 = "..." }`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, BTreeMap<String, Expected>>>);
+
+impl Answers {
+    /// Loads the answers stored at `path`, an absent file standing for no expected answer.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(s) => s.parse(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        fs::write(path, toml::to_string(self)?)?;
+
+        Ok(())
+    }
+
+    pub fn get(&self, day: Day, part: Part) -> Option<&Expected> {
+        self.0
+            .get(&day.year().to_string())
+            .and_then(|days| days.get(&day.name()))
+            .and_then(|parts| parts.get(&Phase::Solve(part).to_string()))
+    }
+
+    /// Records the digest of the answer of every solved part of `records`, keeping the expected answers that still
+    /// match not to change their salt.
+    pub fn record(&mut self, records: &[Record]) {
+        for record in records {
+            if let (Phase::Solve(_), Some(answer)) = (record.phase, &record.answer) {
+                let expected = self
+                    .0
+                    .entry(record.day.year().to_string())
+                    .or_default()
+                    .entry(record.day.name())
+                    .or_default()
+                    .entry(record.phase.to_string())
+                    .or_insert_with(|| Expected::digest(answer));
+
+                if !expected.matches(answer) {
+                    *expected = Expected::digest(answer);
+                }
+            }
+        }
+    }
+
+    /// Checks the answer of every solved part of `records` against the expected one.
+    pub fn verify(&self, records: &[Record]) -> Vec<Verification> {
+        records
+            .iter()
+            .filter_map(|record| {
+                match (record.phase, &record.answer) {
+                    (Phase::Solve(part), Some(answer)) => {
+                        Some(Verification {
+                            day: record.day,
+                            part,
+                            expected: self.get(record.day, part).cloned(),
+                            actual: answer.clone(),
+                        })
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(toml::from_str(s)?)
+    }
+}
+
+/// Answer of a part checked against the expected one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    pub day: Day,
+    pub part: Part,
+    pub expected: Option<Expected>,
+    pub actual: Answer,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Mismatch,
+    /// No answer is expected for the part yet.
+    Unrecorded,
+}
+
+impl Verification {
+    pub fn status(&self) -> Status {
+        match &self.expected {
+            None => Status::Unrecorded,
+            Some(expected) if expected.matches(&self.actual) => Status::Ok,
+            Some(_) => Status::Mismatch,
+        }
+    }
+}
+
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} part {}: ", self.day, self.part)?;
+
+        match &self.expected {
+            Some(expected) => write!(f, "expected {expected}, got {}", self.actual),
+            None => write!(f, "no expected answer, got {}", self.actual),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timing::Timings;
+
+    const ANSWERS: &str = r#"
+[2023.day01]
+part1 = "142"
+part2 = "281"
+"#;
+
+    fn record(part: Part, answer: i64) -> Record {
+        Record {
+            day: Day::from_number(2023, 1).unwrap(),
+            phase: Phase::Solve(part),
+            answer: Some(answer.into()),
+            timings: Timings::default(),
+        }
+    }
+
+    #[test]
+    fn it_verifies_answers() {
+        let answers = ANSWERS.parse::<Answers>().unwrap();
+        let verifications = answers.verify(&[record(Part::One, 142), record(Part::Two, 280)]);
+
+        assert_eq!(
+            vec![Status::Ok, Status::Mismatch],
+            verifications.iter().map(Verification::status).collect::<Vec<_>>()
+        );
+        assert_eq!("2023/day01 part 2: expected 281, got 280", verifications[1].to_string());
+    }
+
+    #[test]
+    fn it_reports_unrecorded_answers() {
+        let verifications = Answers::default().verify(&[record(Part::One, 142)]);
+
+        assert_eq!(Status::Unrecorded, verifications[0].status());
+    }
+
+    #[test]
+    fn it_verifies_recorded_digests() {
+        let mut answers = Answers::default();
+
+        answers.record(&[record(Part::One, 142)]);
+
+        let answers = toml::to_string(&answers).unwrap().parse::<Answers>().unwrap();
+
+        assert!(matches!(
+            answers.get(Day::from_number(2023, 1).unwrap(), Part::One),
+            Some(Expected::Digest { .. })
+        ));
+        assert_eq!(
+            vec![Status::Ok, Status::Mismatch],
+            answers
+                .verify(&[record(Part::One, 142), record(Part::One, 143)])
+                .iter()
+                .map(Verification::status)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_salt_of_unchanged_answers() {
+        let mut answers = Answers::default();
+
+        answers.record(&[record(Part::One, 142)]);
+
+        let recorded = answers.clone();
+
+        answers.record(&[record(Part::One, 142)]);
+
+        assert_eq!(recorded, answers);
+
+        answers.record(&[record(Part::One, 143)]);
+
+        assert_ne!(recorded, answers);
+        assert!(answers
+            .get(Day::from_number(2023, 1).unwrap(), Part::One)
+            .unwrap()
+            .matches(&143.into()));
+    }
+}
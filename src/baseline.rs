@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+use std::{fs, io};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::runner::Record;
+use crate::timing::Phase;
+use crate::Day;
+
+/// Baseline file checked in the repository.
+pub const DEFAULT_BASELINE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/baseline.json");
+
+/// Build profile of the running binary, times measured by a debug build not being comparable to release ones.
+pub const PROFILE: &str = if cfg!(debug_assertions) { "debug" } else { "release" };
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error("the baseline was measured by a {0} build, not a {PROFILE} one")]
+    Profile(String),
+}
+
+/// Median times of the phases of each day along with the build profile they were measured by.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    profile: String,
+    /// Times in nanoseconds, keyed by year, day then phase.
+    times: BTreeMap<String, BTreeMap<String, BTreeMap<String, u64>>>,
+}
+
+impl Default for Baseline {
+    fn default() -> Self {
+        Self {
+            profile: PROFILE.to_owned(),
+            times: BTreeMap::default(),
+        }
+    }
+}
+
+impl Baseline {
+    /// Loads the baseline stored at `path`, an absent file standing for an empty baseline.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(s) => Ok(serde_json::from_str(&s)?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let mut s = serde_json::to_string_pretty(self)?;
+
+        s.push('\n');
+        fs::write(path, s)?;
+
+        Ok(())
+    }
+
+    pub fn get(&self, day: Day, phase: Phase) -> Option<Duration> {
+        self.times
+            .get(&day.year().to_string())
+            .and_then(|days| days.get(&day.name()))
+            .and_then(|phases| phases.get(&phase.to_string()))
+            .map(|nanos| Duration::from_nanos(*nanos))
+    }
+
+    /// Ensures the times were measured by a build of the same profile as the running one, if any.
+    pub fn check_profile(&self) -> Result<(), Error> {
+        if self.profile == PROFILE || self.times.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Profile(self.profile.clone()))
+        }
+    }
+
+    /// Records the median time of every phase of `records` but the input loading, which mostly depends on the
+    /// filesystem.
+    pub fn record(&mut self, records: &[Record]) -> Result<(), Error> {
+        self.check_profile()?;
+        self.profile = PROFILE.to_owned();
+
+        for record in records.iter().filter(|record| record.phase != Phase::Load) {
+            self.times
+                .entry(record.day.year().to_string())
+                .or_default()
+                .entry(record.day.name())
+                .or_default()
+                .insert(record.phase.to_string(), record.timings.median().as_nanos() as u64);
+        }
+
+        Ok(())
+    }
+
+    /// Compares the median time of every recorded phase of `records` against the baseline.
+    pub fn compare(&self, records: &[Record], threshold: f64) -> Result<Vec<Comparison>, Error> {
+        self.check_profile()?;
+
+        let comparisons = records
+            .iter()
+            .filter(|record| record.phase != Phase::Load)
+            .map(|record| {
+                Comparison {
+                    day: record.day,
+                    phase: record.phase,
+                    baseline: self.get(record.day, record.phase),
+                    current: record.timings.median(),
+                    threshold,
+                }
+            })
+            .collect();
+
+        Ok(comparisons)
+    }
+}
+
+/// Time of a phase compared against its baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: Day,
+    pub phase: Phase,
+    pub baseline: Option<Duration>,
+    pub current: Duration,
+    /// Slowdown percentage beyond which the phase is deemed to have regressed.
+    pub threshold: f64,
+}
+
+impl Comparison {
+    /// Returns the relative change from the baseline, in percent.
+    pub fn change(&self) -> Option<f64> {
+        self.baseline
+            .map(|baseline| (self.current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0)
+    }
+
+    pub fn is_regression(&self) -> bool {
+        self.change().is_some_and(|change| change > self.threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timing::Timings;
+    use crate::Part;
+
+    fn record(phase: Phase, millis: u64) -> Record {
+        let mut timings = Timings::default();
+
+        timings.push(Duration::from_millis(millis));
+
+        Record {
+            day: Day::iter().next().unwrap(),
+            phase,
+            answer: None,
+            timings,
+        }
+    }
+
+    #[test]
+    fn it_flags_regressions_beyond_the_threshold() {
+        let mut baseline = Baseline::default();
+
+        baseline
+            .record(&[
+                record(Phase::Load, 1),
+                record(Phase::Parse, 10),
+                record(Phase::Solve(Part::One), 10),
+            ])
+            .unwrap();
+
+        let comparisons = baseline
+            .compare(
+                &[
+                    record(Phase::Load, 100),
+                    record(Phase::Parse, 11),
+                    record(Phase::Solve(Part::One), 13),
+                    record(Phase::Solve(Part::Two), 13),
+                ],
+                20.0,
+            )
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                (Phase::Parse, false),
+                (Phase::Solve(Part::One), true),
+                (Phase::Solve(Part::Two), false)
+            ],
+            comparisons
+                .iter()
+                .map(|comparison| (comparison.phase, comparison.is_regression()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(None, comparisons[2].change());
+    }
+
+    #[test]
+    fn it_refuses_to_mix_build_profiles() {
+        let other = if PROFILE == "debug" { "release" } else { "debug" };
+        let mut baseline = serde_json::from_str::<Baseline>(&format!(
+            r#"{{"profile": "{other}", "times": {{"2023": {{"day01": {{"parse": 10}}}}}}}}"#
+        ))
+        .unwrap();
+
+        assert!(matches!(
+            baseline.compare(&[record(Phase::Parse, 10)], 20.0),
+            Err(Error::Profile(_))
+        ));
+        assert!(matches!(
+            baseline.record(&[record(Phase::Parse, 10)]),
+            Err(Error::Profile(_))
+        ));
+
+        baseline.times.clear();
+
+        assert!(baseline.record(&[record(Phase::Parse, 10)]).is_ok());
+        assert_eq!(PROFILE, baseline.profile);
+    }
+}
@@ -0,0 +1,128 @@
+use std::path::{Path, PathBuf};
+
+use advent_of_code_2023::baseline::{Baseline, DEFAULT_BASELINE_PATH};
+use advent_of_code_2023::config::Config;
+use advent_of_code_2023::{input, runner, DaySelection, Part};
+
+use clap::{Args, Subcommand};
+use eyre::{bail, Result, WrapErr};
+use itertools::Itertools;
+
+use crate::{format_duration, report};
+
+#[derive(Subcommand)]
+pub enum BaselineCommand {
+    /// Record the times of the selected days into the baseline
+    Record(BaselineOpt),
+    /// Compare the times of the selected days against the baseline
+    Check {
+        #[command(flatten)]
+        opt: BaselineOpt,
+        /// Slowdown percentage beyond which a phase is deemed to have regressed
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
+
+#[derive(Args)]
+pub struct BaselineOpt {
+    /// Days to measure: `all`, a day (`7`, `2023/7`, `latest`), a range (`3..7`, `3..=latest`) or a list of them
+    #[arg(default_value = "all")]
+    days: DaySelection,
+    /// Number of runs the median times are computed from
+    #[arg(short, long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+    repeat: u32,
+    /// Baseline file
+    #[arg(long, default_value = DEFAULT_BASELINE_PATH)]
+    file: PathBuf,
+    /// Also measure the days taking minutes to solve, skipped by default
+    #[arg(long)]
+    slow: bool,
+}
+
+fn measure(opt: &BaselineOpt, config: &Config) -> Result<Vec<runner::Record>> {
+    let mut records = Vec::default();
+
+    for day in opt.days.resolve(config.year.value)? {
+        if let Some(reason) = day.slowness().filter(|_| !opt.slow) {
+            eprintln!("skipping {day}: {reason}, pass --slow to measure it");
+            continue;
+        }
+
+        let path = input::get_input_path(day, &config.data_dir.value);
+
+        records.extend(runner::solve_day(day, &path, &[Part::One, Part::Two], opt.repeat).map_err(report)?);
+    }
+
+    Ok(records)
+}
+
+/// Loads the baseline at `path`, ensuring its times are comparable to the ones about to be measured.
+fn load(path: &Path) -> Result<Baseline> {
+    let baseline = Baseline::load(path).wrap_err_with(|| format!("cannot load baseline '{}'", path.display()))?;
+
+    baseline
+        .check_profile()
+        .wrap_err_with(|| format!("cannot measure against baseline '{}'", path.display()))?;
+
+    Ok(baseline)
+}
+
+pub fn run(command: BaselineCommand, config: &Config) -> Result<()> {
+    match command {
+        BaselineCommand::Record(opt) => {
+            let mut baseline = load(&opt.file)?;
+            let records = measure(&opt, config)?;
+
+            baseline.record(&records)?;
+            baseline
+                .save(&opt.file)
+                .wrap_err_with(|| format!("cannot save baseline '{}'", opt.file.display()))?;
+
+            let days = records.iter().map(|record| record.day).dedup().count();
+
+            println!("recorded {days} days into '{}'", opt.file.display());
+        }
+        BaselineCommand::Check { opt, threshold } => {
+            let baseline = load(&opt.file)?;
+            let records = measure(&opt, config)?;
+            let comparisons = baseline.compare(&records, threshold)?;
+
+            println!(
+                "{:<10} {:<6} {:>10} {:>10} {:>8}  status",
+                "day", "phase", "baseline", "current", "change"
+            );
+
+            for comparison in &comparisons {
+                let status = match comparison.baseline {
+                    None => "new",
+                    Some(_) if comparison.is_regression() => "regressed",
+                    Some(_) => "ok",
+                };
+
+                println!(
+                    "{:<10} {:<6} {:>10} {:>10} {:>8}  {status}",
+                    comparison.day,
+                    comparison.phase,
+                    comparison.baseline.map(format_duration).unwrap_or_default(),
+                    format_duration(comparison.current),
+                    comparison
+                        .change()
+                        .map(|change| format!("{change:+.1}%"))
+                        .unwrap_or_default()
+                );
+            }
+
+            let regressions = comparisons
+                .iter()
+                .filter(|comparison| comparison.is_regression())
+                .count();
+
+            if regressions > 0 {
+                bail!("{regressions} phases regressed by more than {threshold}%");
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use advent_of_code_2023::answers::{Answers, Status};
+use advent_of_code_2023::baseline::{Baseline, DEFAULT_BASELINE_PATH};
+use advent_of_code_2023::config::Config;
+use advent_of_code_2023::examples::{self, Example};
+use advent_of_code_2023::ledger::Ledger;
+use advent_of_code_2023::timing::Phase;
+use advent_of_code_2023::{input, registry, runner, Day, Part};
+
+use clap::Args;
+use eyre::{Result, WrapErr};
+use strum::IntoEnumIterator;
+
+use crate::{catch, format_duration};
+
+#[derive(Args)]
+pub struct CalendarOpt {
+    /// Solve the puzzle inputs to verify the recorded answers, which takes minutes for the slowest days
+    #[arg(long)]
+    verify: bool,
+    /// Baseline file holding the last measured runtimes
+    #[arg(long, default_value = DEFAULT_BASELINE_PATH)]
+    baseline: PathBuf,
+}
+
+/// Returns whether `day` solves the example at `path` as expected, a panic counting as a failure.
+fn passes(day: Day, path: &Path) -> bool {
+    let Ok(example) = Example::load(path) else {
+        return false;
+    };
+
+    catch(|| {
+        advent_of_code_2023::parse(day, &example.input).is_ok_and(|model| {
+            example
+                .expected
+                .iter()
+                .all(|(part, expected)| advent_of_code_2023::solve(day, *part, &model).to_string() == *expected)
+        })
+    })
+    .unwrap_or(false)
+}
+
+/// Returns the parts of `day` having a registered main solver, e.g. `1 2` or `1 -`.
+fn solvers(day: Day) -> String {
+    Part::iter()
+        .map(|part| {
+            if registry::solution(day, part).is_some() {
+                part.to_string()
+            } else {
+                "-".to_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns how many examples of `day` pass out of the ones found, e.g. `2/2`.
+fn examples(day: Day, config: &Config) -> Result<String> {
+    let dir = examples::get_examples_dir(day, &config.examples_dir.value);
+    let paths = examples::find_examples(&dir).wrap_err_with(|| format!("cannot list examples '{}'", dir.display()))?;
+
+    if paths.is_empty() {
+        return Ok("-".to_owned());
+    }
+
+    let passed = paths.iter().filter(|path| passes(day, path)).count();
+
+    Ok(format!("{passed}/{}", paths.len()))
+}
+
+/// Returns how many answers of `day` are recorded or, when `verify` is set, how many of those the day finds.
+fn answers(day: Day, answers: &Answers, verify: bool, config: &Config) -> String {
+    let recorded = Part::iter().filter(|part| answers.get(day, *part).is_some()).count();
+
+    if recorded == 0 {
+        return "-".to_owned();
+    }
+
+    if !verify {
+        return format!("{recorded} recorded");
+    }
+
+    let path = input::get_input_path(day, &config.data_dir.value);
+
+    if !path.exists() {
+        return "no input".to_owned();
+    }
+
+    let parts = Part::iter().collect::<Vec<_>>();
+
+    match catch(|| runner::solve_day(day, &path, &parts, 1)) {
+        Ok(Ok(records)) => {
+            let verified = answers
+                .verify(&records)
+                .iter()
+                .filter(|verification| verification.status() == Status::Ok)
+                .count();
+
+            format!("{verified}/{recorded}")
+        }
+        _ => "failed".to_owned(),
+    }
+}
+
+/// Returns the last measured time of the parsing and solving of `day`.
+fn runtime(day: Day, baseline: &Baseline) -> Option<Duration> {
+    [Phase::Parse, Phase::Solve(Part::One), Phase::Solve(Part::Two)]
+        .into_iter()
+        .map(|phase| baseline.get(day, phase))
+        .sum()
+}
+
+pub fn run(opt: CalendarOpt, config: &Config) -> Result<()> {
+    let year = config.year.value;
+    let answers_file = &config.answers_file.value;
+    let answers_by_day =
+        Answers::load(answers_file).wrap_err_with(|| format!("cannot load answers '{}'", answers_file.display()))?;
+    let baseline =
+        Baseline::load(&opt.baseline).wrap_err_with(|| format!("cannot load baseline '{}'", opt.baseline.display()))?;
+    let ledger = match &config.ledger_file.value {
+        Some(path) => {
+            Ledger::load(path).wrap_err_with(|| format!("cannot load the guesses from '{}'", path.display()))?
+        }
+        None => Ledger::default(),
+    };
+    let mut stars = 0;
+
+    println!(
+        "{:<10} {:<8} {:<6} {:<9} {:<11} {:>10}",
+        year, "solvers", "stars", "examples", "answers", "runtime"
+    );
+
+    for n in 1..=25 {
+        let Some(day) = Day::from_number(year, n) else {
+            println!(
+                "{:<10} {:<8} {:<6} {:<9} {:<11} {:>10}",
+                format!("day{n:02}"),
+                "- -",
+                "",
+                "-",
+                "-",
+                "-"
+            );
+            continue;
+        };
+        let solved = Part::iter().filter(|part| ledger.is_solved(day, *part)).count();
+
+        stars += solved;
+
+        println!(
+            "{:<10} {:<8} {:<6} {:<9} {:<11} {:>10}",
+            day.name(),
+            solvers(day),
+            "*".repeat(solved),
+            examples(day, config)?,
+            answers(day, &answers_by_day, opt.verify, config),
+            runtime(day, &baseline)
+                .map(format_duration)
+                .unwrap_or_else(|| "-".to_owned())
+        );
+    }
+
+    println!(
+        "\n{} days implemented, {stars} stars",
+        Day::iter().filter(|day| day.year() == year).count()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use advent_of_code_2023::config::Config;
+
+use clap::Subcommand;
+use eyre::Result;
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Print the effective configuration and where each value comes from
+    Show,
+}
+
+pub fn run(command: ConfigCommand, config: &Config) -> Result<()> {
+    match command {
+        ConfigCommand::Show => {
+            let entries = config.entries();
+            let width = entries
+                .iter()
+                .filter_map(|(_, value, _)| value.as_ref().map(String::len))
+                .max()
+                .unwrap_or_default();
+
+            println!("{:<12} {:<width$}  source", "key", "value");
+
+            for (key, value, source) in entries {
+                println!("{key:<12} {:<width$}  {source}", value.as_deref().unwrap_or("-"));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use std::fs;
+use std::path::PathBuf;
+
+use advent_of_code_2023::config::Config;
+use advent_of_code_2023::examples::{self, Example};
+use advent_of_code_2023::{puzzle, DayArg, Part};
+
+use clap::Args;
+use eyre::{bail, Result, WrapErr};
+use itertools::Itertools;
+
+#[derive(Args)]
+pub struct ExtractOpt {
+    /// Day to extract the examples of, e.g. `7`, `2023/7` or `latest`
+    day: DayArg,
+    /// Saved puzzle page to read [default: the one downloaded by `aoc fetch --puzzle`]
+    #[arg(long)]
+    page: Option<PathBuf>,
+    /// Overwrite the examples already extracted
+    #[arg(short, long)]
+    force: bool,
+}
+
+/// Returns the file name of `example`, after the parts it is an example of.
+fn file_name(example: &Example) -> &'static str {
+    match example.expected.keys().collect::<Vec<_>>()[..] {
+        [Part::One] => "part1.txt",
+        [Part::Two] => "part2.txt",
+        _ => "example.txt",
+    }
+}
+
+pub fn run(opt: ExtractOpt, config: &Config) -> Result<()> {
+    let (year, n) = opt.day.number(config.year.value)?;
+    let page_path = opt
+        .page
+        .unwrap_or_else(|| puzzle::get_page_path(year, n, &config.data_dir.value));
+    let page = fs::read_to_string(&page_path).wrap_err_with(|| {
+        format!(
+            "cannot read the puzzle page '{}', download it with `aoc fetch --puzzle {year}/{n}`",
+            page_path.display()
+        )
+    })?;
+    let (examples, blocks) = puzzle::extract_examples(&page)
+        .into_iter()
+        .partition::<Vec<_>, _>(|example| !example.expected.is_empty());
+
+    if examples.is_empty() {
+        bail!("no example with an expected answer in '{}'", page_path.display());
+    }
+
+    let dir = examples::get_examples_dir_by_number(year, n, &config.examples_dir.value);
+
+    fs::create_dir_all(&dir).wrap_err_with(|| format!("cannot create '{}'", dir.display()))?;
+
+    for example in examples {
+        let path = dir.join(file_name(&example));
+
+        if path.exists() && !opt.force {
+            println!("kept '{}', pass --force to overwrite it", path.display());
+            continue;
+        }
+
+        fs::write(&path, example.to_string()).wrap_err_with(|| format!("cannot write '{}'", path.display()))?;
+
+        let expected = example
+            .expected
+            .iter()
+            .map(|(part, answer)| format!("{answer} from part {part}"))
+            .join(", ");
+
+        println!(
+            "wrote '{}' expecting {expected}, review it before committing",
+            path.display()
+        );
+    }
+
+    if !blocks.is_empty() {
+        println!("skipped {} code blocks without an expected answer", blocks.len());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use advent_of_code_2023::config::Config;
+use advent_of_code_2023::website::Fetched;
+use advent_of_code_2023::DayArg;
+
+use clap::Args;
+use eyre::{Result, WrapErr};
+
+use crate::website::WebsiteOpt;
+
+#[derive(Args)]
+pub struct FetchOpt {
+    /// Days to download the input of, e.g. `7`, `day07` or `2023/7`
+    #[arg(required = true)]
+    days: Vec<DayArg>,
+    /// Download the puzzle page as well, to extract examples from or read offline
+    #[arg(long)]
+    puzzle: bool,
+    #[command(flatten)]
+    website: WebsiteOpt,
+}
+
+pub fn run(opt: FetchOpt, config: &Config) -> Result<()> {
+    let mut client = opt.website.client(config)?;
+
+    for day in opt.days {
+        let (year, day) = day.number(config.year.value)?;
+
+        match client
+            .fetch_input(year, day, &config.data_dir.value)
+            .wrap_err_with(|| format!("cannot fetch the input of day {day} of {year}"))?
+        {
+            Fetched::Cached(path) => println!("day {day} of {year} is already in '{}'", path.display()),
+            Fetched::Downloaded(path) => println!("downloaded day {day} of {year} into '{}'", path.display()),
+        }
+
+        if opt.puzzle {
+            match client
+                .fetch_puzzle(year, day, &config.data_dir.value)
+                .wrap_err_with(|| format!("cannot fetch the puzzle of day {day} of {year}"))?
+            {
+                Fetched::Cached(path) => {
+                    println!("the puzzle of day {day} of {year} is already in '{}'", path.display())
+                }
+                Fetched::Downloaded(path) => {
+                    println!("downloaded the puzzle of day {day} of {year} into '{}'", path.display())
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use advent_of_code_2023::{registry, Day};
+
+use eyre::Result;
+use itertools::Itertools;
+
+pub fn run() -> Result<()> {
+    println!("{:<6} days", "year");
+
+    for year in Day::years() {
+        let days = Day::iter().filter(|day| day.year() == year).map(Day::number).join(" ");
+
+        println!("{year:<6} {days}");
+    }
+
+    println!();
+    println!("{:<10} {:<6} {:<24} variant", "day", "phase", "function");
+
+    for parser in registry::parsers() {
+        let day = Day::from_number(parser.year, parser.day).expect("days are the ones having a parser");
+
+        println!("{day:<10} {:<6} {}", "parse", parser.name);
+
+        let solutions =
+            registry::solutions().filter(|solution| solution.year == parser.year && solution.day == parser.day);
+
+        for solution in solutions {
+            let phase = format!("part{}", solution.part);
+
+            match solution.variant {
+                Some(variant) => println!("{day:<10} {phase:<6} {:<24} {variant}", solution.name),
+                None => println!("{day:<10} {phase:<6} {}", solution.name),
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use std::any::Any;
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use advent_of_code_2023::config::{user_dir, Config, Layer, Source};
+use advent_of_code_2023::{runner, ParseError};
+
+use clap::{Parser, Subcommand};
+use color_eyre::{Section, SectionExt};
+use eyre::{Report, Result, WrapErr};
+
+mod baseline;
+mod calendar;
+mod config;
+mod extract;
+mod fetch;
+mod list;
+mod new;
+mod output;
+mod read;
+mod run;
+mod submit;
+mod verify;
+mod watch;
+mod website;
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+pub struct Opt {
+    /// Directory holding the puzzle inputs [default: from the configuration]
+    #[arg(long, global = true)]
+    data_dir: Option<PathBuf>,
+    /// Year of the event to work on [default: from the configuration]
+    #[arg(long, global = true)]
+    year: Option<u16>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzles of the selected days
+    Run(run::RunOpt),
+    /// Record or check the performance baseline
+    #[command(subcommand)]
+    Baseline(baseline::BaselineCommand),
+    /// Check the answers of the selected days against the expected ones
+    Verify(verify::VerifyOpt),
+    /// Scaffold the module, registration and input file of a new day
+    New(new::NewOpt),
+    /// List the registered parsers and solvers
+    List,
+    /// Show every day of the year with its solvers, stars, passing examples, verified answers and last runtime
+    Calendar(calendar::CalendarOpt),
+    /// Download the puzzle inputs, and optionally pages, of the selected days
+    Fetch(fetch::FetchOpt),
+    /// Render the puzzle description of a day from its saved page
+    Read(read::ReadOpt),
+    /// Extract the example inputs and expected answers of a day from its puzzle page into the examples directory
+    Extract(extract::ExtractOpt),
+    /// Solve a part and submit its answer
+    Submit(submit::SubmitOpt),
+    /// Solve a day again whenever its input, examples or source change
+    Watch(watch::WatchOpt),
+    /// Inspect the configuration merged from `aoc.toml` files and `AOC_*` environment variables
+    #[command(subcommand)]
+    Config(config::ConfigCommand),
+}
+
+/// Renders the input line pointed by `error`, underlining the offending token.
+fn render_snippet(error: &ParseError, path: &Path, input: &str) -> String {
+    let text = input.lines().nth(error.line - 1).unwrap_or_default();
+    let line = error.line.to_string();
+    let margin = " ".repeat(line.len());
+    let offset = " ".repeat(error.columns.start);
+    let underline = "^".repeat(error.columns.len().max(1));
+
+    format!(
+        "{margin}--> {}:{}:{}\n{margin} |\n{line} | {text}\n{margin} | {offset}{underline} expected {}",
+        path.display(),
+        error.line,
+        error.columns.start + 1,
+        error.expected
+    )
+}
+
+/// Converts a runner error into a report, pointing parse errors in the input.
+fn report(error: runner::Error) -> Report {
+    let snippet = match &error {
+        runner::Error::Parse { path, input, source } => Some(render_snippet(source, path, input)),
+        runner::Error::Input { .. } => None,
+    };
+
+    match snippet {
+        Some(snippet) => Report::new(error).section(snippet.header("Input:")),
+        None => Report::new(error),
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+/// Runs `f`, turning a panic into its message rather than printing it, the panics raised elsewhere being printed as
+/// usual.
+fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    let hook = panic::take_hook();
+
+    panic::set_hook(Box::new(|_| {}));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+
+    panic::set_hook(hook);
+
+    result.map_err(|payload| format!("panicked: {}", panic_message(&*payload)))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause")
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    let opt = Opt::parse();
+    let mut config = Config::load(&env::current_dir()?, user_dir().as_deref(), |name| env::var(name).ok())
+        .wrap_err("cannot load the configuration")?;
+
+    config.merge(
+        Layer {
+            data_dir: opt.data_dir,
+            year: opt.year,
+            ..Layer::default()
+        },
+        |key| Source::Flag(format!("--{key}")),
+    );
+
+    match opt.command {
+        Command::Run(run_opt) => run::run(run_opt, &config),
+        Command::Baseline(command) => baseline::run(command, &config),
+        Command::Verify(verify_opt) => verify::run(verify_opt, &config),
+        Command::New(new_opt) => new::run(new_opt, &config),
+        Command::List => list::run(),
+        Command::Calendar(calendar_opt) => calendar::run(calendar_opt, &config),
+        Command::Fetch(fetch_opt) => fetch::run(fetch_opt, &config),
+        Command::Read(read_opt) => read::run(read_opt, &config),
+        Command::Extract(extract_opt) => extract::run(extract_opt, &config),
+        Command::Submit(submit_opt) => submit::run(submit_opt, &config),
+        Command::Watch(watch_opt) => watch::run(watch_opt, &config),
+        Command::Config(command) => config::run(command, &config),
+    }
+}
@@ -0,0 +1,172 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use advent_of_code_2023::config::Config;
+use advent_of_code_2023::input;
+
+use clap::Args;
+use eyre::{bail, Result, WrapErr};
+
+/// Root of the crate the days are scaffolded into.
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+const TEMPLATE: &str = include_str!("templates/day.rs.template");
+
+#[derive(Args)]
+pub struct NewOpt {
+    /// Day of the month to scaffold
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+/// Returns the directory holding the module of each year.
+fn years_dir() -> PathBuf {
+    PathBuf::from(CRATE_DIR).join("src").join("years")
+}
+
+/// Returns the path of the module of the `n`th day of `year`.
+pub fn module_path(year: u16, n: u8) -> PathBuf {
+    years_dir().join(format!("y{year}")).join(format!("day{n:02}.rs"))
+}
+
+/// Inserts `line` among the lines of `source` starting with `prefix`, keeping them sorted.
+fn insert_sorted(source: &str, prefix: &str, line: &str) -> Result<String> {
+    let lines = source.lines().collect::<Vec<_>>();
+    let matching = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with(prefix))
+        .collect::<Vec<_>>();
+
+    let Some((last, _)) = matching.last() else {
+        bail!("no line starting with '{prefix}' to insert '{}' next to", line.trim());
+    };
+
+    if matching.iter().any(|(_, l)| **l == line) {
+        bail!("'{}' is already registered", line.trim());
+    }
+
+    let index = matching.iter().find(|(_, l)| **l > line).map_or(last + 1, |(i, _)| *i);
+
+    let mut lines = lines.into_iter().map(str::to_owned).collect::<Vec<_>>();
+
+    lines.insert(index, line.to_owned());
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Declares the module of `day`, its solver functions registering themselves.
+fn register_module(days: &str, day: &str) -> Result<String> {
+    insert_sorted(days, "pub(crate) mod ", &format!("pub(crate) mod {day};"))
+}
+
+/// Declares the module of `year`, holding the modules of its days.
+fn register_year(years: &str, year: &str) -> Result<String> {
+    insert_sorted(years, "mod ", &format!("mod {year};"))
+}
+
+fn create(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).wrap_err_with(|| format!("cannot write '{}'", path.display()))
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).wrap_err_with(|| format!("cannot read '{}'", path.display()))
+}
+
+pub fn run(opt: NewOpt, config: &Config) -> Result<()> {
+    let year = config.year.value;
+    let day = format!("day{:02}", opt.day);
+    let year_module = format!("y{year}");
+    let years_dir = years_dir();
+    let module_path = module_path(year, opt.day);
+    let input_path = input::get_input_path_by_number(year, opt.day, &config.data_dir.value);
+    let days_path = years_dir.join(format!("{year_module}.rs"));
+    let years_path = years_dir.with_extension("rs");
+
+    for path in [&module_path, &input_path] {
+        if path.exists() {
+            bail!("'{}' already exists", path.display());
+        }
+    }
+
+    // registers the modules before writing anything not to leave the crate half-scaffolded, the first day of a year
+    // declaring the module of the year
+    let (days, years) = if days_path.exists() {
+        (register_module(&read(&days_path)?, &day)?, None)
+    } else {
+        (
+            format!("pub(crate) mod {day};\n"),
+            Some(register_year(&read(&years_path)?, &year_module)?),
+        )
+    };
+
+    for path in [&module_path, &input_path] {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).wrap_err_with(|| format!("cannot create '{}'", dir.display()))?;
+        }
+    }
+
+    let template = TEMPLATE
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &opt.day.to_string());
+
+    create(&module_path, &template)?;
+    create(&input_path, "")?;
+    create(&days_path, &days)?;
+
+    let mut written = vec![&module_path, &input_path, &days_path];
+
+    if let Some(years) = years {
+        create(&years_path, &years)?;
+        written.push(&years_path);
+    }
+
+    for path in written {
+        println!("wrote '{}'", path.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS: &str = "\
+pub(crate) mod day01;
+pub(crate) mod day03;
+";
+
+    #[test]
+    fn it_registers_days_in_order() {
+        assert_eq!(
+            "\
+pub(crate) mod day01;
+pub(crate) mod day02;
+pub(crate) mod day03;
+",
+            register_module(DAYS, "day02").unwrap()
+        );
+        assert_eq!(
+            "\
+pub(crate) mod day01;
+pub(crate) mod day03;
+pub(crate) mod day04;
+",
+            register_module(DAYS, "day04").unwrap()
+        );
+    }
+
+    #[test]
+    fn it_registers_years_in_order() {
+        assert_eq!(
+            "mod y2022;\nmod y2023;\n",
+            register_year("mod y2023;\n", "y2022").unwrap()
+        );
+    }
+
+    #[test]
+    fn it_refuses_to_register_a_day_twice() {
+        assert!(register_module(DAYS, "day03").is_err());
+    }
+}
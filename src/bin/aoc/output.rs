@@ -0,0 +1,140 @@
+use std::error::Error;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use advent_of_code_2023::config::Config;
+use advent_of_code_2023::runner::Record;
+use advent_of_code_2023::timing::Phase;
+use advent_of_code_2023::Day;
+
+use clap::ValueEnum;
+use eyre::{eyre, Result};
+use serde::Serialize;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable table
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+    /// Comma-separated values with a header
+    Csv,
+}
+
+impl Format {
+    /// Returns the default format set by `config`.
+    pub fn from_config(config: &Config) -> Result<Self> {
+        Self::from_str(&config.format.value, true).map_err(|e| {
+            eyre!(
+                "invalid format '{}' ({}): {e}",
+                config.format.value,
+                config.format.source
+            )
+        })
+    }
+}
+
+/// Flat outcome of a phase, the error fields being only set when the day could not be solved.
+#[derive(Serialize)]
+struct Row {
+    day: String,
+    part: Option<u8>,
+    phase: Option<String>,
+    answer: Option<String>,
+    input: String,
+    min_ns: Option<u64>,
+    median_ns: Option<u64>,
+    max_ns: Option<u64>,
+    error: Option<String>,
+}
+
+/// Writer of records in a machine-readable format, flushed after each day for streaming consumers.
+pub enum RecordWriter {
+    Json(io::Stdout),
+    Csv(Box<csv::Writer<io::Stdout>>),
+}
+
+impl RecordWriter {
+    /// Returns the writer of `format`, if machine-readable.
+    pub fn new(format: Format) -> Option<Self> {
+        match format {
+            Format::Text => None,
+            Format::Json => Some(Self::Json(io::stdout())),
+            Format::Csv => Some(Self::Csv(Box::new(csv::Writer::from_writer(io::stdout())))),
+        }
+    }
+
+    /// Writes `records` read from `path`, along with their timings if `timings` is set.
+    pub fn write_records(&mut self, records: &[Record], path: &Path, timings: bool) -> Result<()> {
+        for record in records.iter().filter(|record| timings || record.phase != Phase::Load) {
+            let part = match record.phase {
+                Phase::Solve(part) => Some(part.number()),
+                _ => None,
+            };
+            let nanos = |duration: Duration| timings.then_some(duration.as_nanos() as u64);
+
+            self.write(Row {
+                day: record.day.to_string(),
+                part,
+                phase: Some(record.phase.to_string()),
+                answer: record.answer.as_ref().map(ToString::to_string),
+                input: path.display().to_string(),
+                min_ns: nanos(record.timings.min()),
+                median_ns: nanos(record.timings.median()),
+                max_ns: nanos(record.timings.max()),
+                error: None,
+            })?;
+        }
+
+        self.flush()
+    }
+
+    pub fn write_error(&mut self, day: Day, path: &Path, error: &dyn Error) -> Result<()> {
+        let mut message = error.to_string();
+        let mut source = error.source();
+
+        while let Some(error) = source {
+            message = format!("{message}: {error}");
+            source = error.source();
+        }
+
+        self.write(Row {
+            day: day.to_string(),
+            part: None,
+            phase: None,
+            answer: None,
+            input: path.display().to_string(),
+            min_ns: None,
+            median_ns: None,
+            max_ns: None,
+            error: Some(message),
+        })?;
+
+        self.flush()
+    }
+
+    fn write(&mut self, row: Row) -> Result<()> {
+        match self {
+            Self::Json(stdout) => {
+                let mut stdout = stdout.lock();
+
+                serde_json::to_writer(&mut stdout, &row)?;
+                writeln!(stdout)?;
+            }
+            Self::Csv(writer) => writer.serialize(row)?,
+        }
+
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        match self {
+            Self::Json(stdout) => stdout.flush()?,
+            Self::Csv(writer) => writer.flush()?,
+        }
+
+        Ok(())
+    }
+}
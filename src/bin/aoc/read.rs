@@ -0,0 +1,68 @@
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+
+use advent_of_code_2023::config::Config;
+use advent_of_code_2023::puzzle::{self, Style};
+use advent_of_code_2023::DayArg;
+
+use clap::{Args, ValueEnum};
+use eyre::{Result, WrapErr};
+
+#[derive(Args)]
+pub struct ReadOpt {
+    /// Day to read the puzzle of, e.g. `7`, `day07`, `2023/7` or `latest`
+    day: DayArg,
+    /// Rendering of the puzzle [default: terminal when printing to one, plain otherwise]
+    #[arg(short, long, value_enum)]
+    style: Option<StyleArg>,
+    /// Number of columns to wrap the text at, except in markdown
+    #[arg(short, long, default_value_t = 100)]
+    width: usize,
+    /// Saved puzzle page to read [default: the one downloaded by `aoc fetch --puzzle`]
+    #[arg(long)]
+    page: Option<PathBuf>,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum StyleArg {
+    /// Text with bold emphasis and colored code
+    Terminal,
+    /// Unstyled text, e.g. for pagers
+    Plain,
+    /// Markdown document, links being reference-style
+    Markdown,
+}
+
+impl From<StyleArg> for Style {
+    fn from(style: StyleArg) -> Self {
+        match style {
+            StyleArg::Terminal => Self::Terminal,
+            StyleArg::Plain => Self::Plain,
+            StyleArg::Markdown => Self::Markdown,
+        }
+    }
+}
+
+pub fn run(opt: ReadOpt, config: &Config) -> Result<()> {
+    let (year, n) = opt.day.number(config.year.value)?;
+    let page_path = opt
+        .page
+        .unwrap_or_else(|| puzzle::get_page_path(year, n, &config.data_dir.value));
+    let page = fs::read_to_string(&page_path).wrap_err_with(|| {
+        format!(
+            "cannot read the puzzle page '{}', download it with `aoc fetch --puzzle {year}/{n}`",
+            page_path.display()
+        )
+    })?;
+    let style = match opt.style {
+        Some(style) => style.into(),
+        None if io::stdout().is_terminal() => Style::Terminal,
+        None => Style::Plain,
+    };
+    let url = format!("{}/{year}/day/{n}", config.base_url.value.trim_end_matches('/'));
+
+    print!("{}", puzzle::render(&page, &url, style, opt.width));
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use advent_of_code_2023::config::Config;
+use advent_of_code_2023::runner::{self, Record};
+use advent_of_code_2023::timing::{Phase, Timings};
+use advent_of_code_2023::{input, Answer, DaySelection, Part};
+
+use clap::builder::PossibleValue;
+use clap::{Args, ValueEnum};
+use eyre::{bail, eyre, Result};
+use strum::IntoEnumIterator;
+
+use crate::output::{Format, RecordWriter};
+use crate::{catch, format_duration, report};
+
+#[derive(Args)]
+pub struct RunOpt {
+    /// Days to solve: `all`, a day (`7`, `2023/7`, `latest`), a range (`3..7`, `3..=latest`) or a list of them
+    #[arg(default_value = "all")]
+    days: DaySelection,
+    /// Parts to solve
+    #[arg(short, long, value_enum, default_value_t = PartSelection::Both)]
+    part: PartSelection,
+    /// Puzzle input to solve, `-` for the standard input [default: <DATA_DIR>/<DAY>.txt]
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// Report the time taken to load, parse and solve each day
+    #[arg(short, long)]
+    time: bool,
+    /// Number of runs the reported times are computed from
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..), requires = "time")]
+    repeat: u32,
+    /// Output format, machine-readable ones reporting failing days instead of stopping at the first one [default:
+    /// from the configuration]
+    #[arg(short, long, value_enum)]
+    format: Option<Format>,
+}
+
+/// Parts given on the command line, either one of them or both.
+#[derive(Copy, Clone)]
+enum PartSelection {
+    Part(Part),
+    Both,
+}
+
+impl PartSelection {
+    fn parts(self) -> Vec<Part> {
+        match self {
+            Self::Part(part) => vec![part],
+            Self::Both => Part::iter().collect(),
+        }
+    }
+}
+
+impl ValueEnum for PartSelection {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Part(Part::One), Self::Part(Part::Two), Self::Both]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            Self::Part(part) => part.to_possible_value(),
+            Self::Both => Some(PossibleValue::new("both")),
+        }
+    }
+}
+
+fn print_answers(records: &[Record]) {
+    println!("{:<10} {:<6} {:<16} {:>10}", "day", "phase", "answer", "time");
+
+    for record in records.iter().filter(|record| record.phase != Phase::Load) {
+        println!(
+            "{:<10} {:<6} {:<16} {:>10}",
+            record.day,
+            record.phase,
+            record.answer.as_ref().map(Answer::to_string).unwrap_or_default(),
+            format_duration(record.timings.median())
+        );
+    }
+}
+
+fn print_timings(records: &[Record]) {
+    println!(
+        "{:<10} {:<6} {:<16} {:>10} {:>10} {:>10}",
+        "day", "phase", "answer", "min", "median", "max"
+    );
+
+    for record in records {
+        println!(
+            "{:<10} {:<6} {:<16} {:>10} {:>10} {:>10}",
+            record.day,
+            record.phase,
+            record.answer.as_ref().map(Answer::to_string).unwrap_or_default(),
+            format_duration(record.timings.min()),
+            format_duration(record.timings.median()),
+            format_duration(record.timings.max())
+        );
+    }
+
+    let total = |f: fn(&Timings) -> Duration| format_duration(records.iter().map(|record| f(&record.timings)).sum());
+
+    println!(
+        "{:<10} {:<6} {:<16} {:>10} {:>10} {:>10}",
+        "total",
+        "",
+        "",
+        total(Timings::min),
+        total(Timings::median),
+        total(Timings::max)
+    );
+}
+
+pub fn run(opt: RunOpt, config: &Config) -> Result<()> {
+    let days = opt.days.resolve(config.year.value)?;
+
+    if opt.input.is_some() && days.len() > 1 {
+        bail!("an input can only be given when solving a single day");
+    }
+
+    if opt.repeat > 1 && opt.input.as_deref() == Some(Path::new(input::STDIN_PATH)) {
+        bail!("the standard input cannot be read repeatedly");
+    }
+
+    let parts = opt.part.parts();
+    let mut writer = RecordWriter::new(opt.format.map_or_else(|| Format::from_config(config), Ok)?);
+    let mut records = Vec::default();
+    let mut failures = 0;
+
+    for day in days {
+        let path = opt
+            .input
+            .clone()
+            .unwrap_or_else(|| input::get_input_path(day, &config.data_dir.value));
+
+        // a panicking solver is reported like a failing day so that machine-readable outputs go on with the next one
+        match (catch(|| runner::solve_day(day, &path, &parts, opt.repeat)), &mut writer) {
+            (Ok(Ok(day_records)), Some(writer)) => writer.write_records(&day_records, &path, opt.time)?,
+            (Ok(Ok(day_records)), None) => records.extend(day_records),
+            (Ok(Err(error)), Some(writer)) => {
+                writer.write_error(day, &path, &error)?;
+                failures += 1;
+            }
+            (Err(message), Some(writer)) => {
+                writer.write_error(day, &path, &*eyre!(message))?;
+                failures += 1;
+            }
+            (Ok(Err(error)), None) => return Err(report(error)),
+            (Err(message), None) => bail!("cannot solve {day}: {message}"),
+        }
+    }
+
+    match writer {
+        Some(_) if failures > 0 => bail!("{failures} days could not be solved"),
+        Some(_) => {}
+        None if opt.time => print_timings(&records),
+        None => print_answers(&records),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use advent_of_code_2023::config::Config;
+use advent_of_code_2023::ledger::Ledger;
+use advent_of_code_2023::website::Verdict;
+use advent_of_code_2023::{input, runner, DayArg, Part};
+
+use clap::Args;
+use eyre::{bail, eyre, Result, WrapErr};
+
+use crate::report;
+use crate::website::WebsiteOpt;
+
+#[derive(Args)]
+pub struct SubmitOpt {
+    /// Day to submit the answer of, e.g. `7`, `day07`, `2023/7` or `latest`
+    day: DayArg,
+    /// Part to submit the answer of
+    #[arg(short, long, value_enum)]
+    part: Part,
+    #[command(flatten)]
+    website: WebsiteOpt,
+}
+
+pub fn run(opt: SubmitOpt, config: &Config) -> Result<()> {
+    let data_dir = &config.data_dir.value;
+    let day = opt.day.resolve(config.year.value)?;
+    let part = opt.part;
+    let path = input::get_input_path(day, data_dir);
+    let answer = runner::solve_day(day, &path, &[part], 1)
+        .map_err(report)?
+        .pop()
+        .and_then(|record| record.answer)
+        .expect("solved parts have an answer");
+
+    let ledger_path = config
+        .ledger_file
+        .value
+        .as_ref()
+        .ok_or_else(|| eyre!("no ledger file to record the guesses into, set one with `ledger-file`"))?;
+    let mut ledger =
+        Ledger::load(ledger_path).wrap_err_with(|| format!("cannot load ledger '{}'", ledger_path.display()))?;
+
+    ledger
+        .check(day, part, &answer)
+        .wrap_err_with(|| format!("refusing to submit '{answer}' to {day} part {part}"))?;
+
+    let verdict = opt
+        .website
+        .client(config)?
+        .submit_answer(day.year(), day.number(), part, &answer)
+        .wrap_err_with(|| format!("cannot submit '{answer}' to {day} part {part}"))?;
+
+    ledger.record(day, part, &answer, verdict);
+    ledger
+        .save(ledger_path)
+        .wrap_err_with(|| format!("cannot save ledger '{}'", ledger_path.display()))?;
+
+    match verdict {
+        Verdict::Correct => println!("'{answer}' is the right answer to {day} part {part}"),
+        Verdict::TooHigh => bail!("'{answer}' is too high"),
+        Verdict::TooLow => bail!("'{answer}' is too low"),
+        Verdict::Wrong => bail!("'{answer}' is not the right answer"),
+        Verdict::Wait(wait) => bail!("an answer was submitted too recently, wait {}s", wait.as_secs()),
+        Verdict::WrongLevel => {
+            bail!("{day} part {part} cannot be submitted, it is either solved already or locked until part 1 is")
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use std::path::PathBuf;
+
+use advent_of_code_2023::answers::{Answers, Expected, Status};
+use advent_of_code_2023::config::Config;
+use advent_of_code_2023::{input, runner, DaySelection, Part};
+
+use clap::Args;
+use eyre::{bail, Result, WrapErr};
+
+use crate::report;
+
+#[derive(Args)]
+pub struct VerifyOpt {
+    /// Days to verify: `all`, a day (`7`, `2023/7`, `latest`), a range (`3..7`, `3..=latest`) or a list of them
+    #[arg(default_value = "all")]
+    days: DaySelection,
+    /// Answers file [default: from the configuration]
+    #[arg(long)]
+    file: Option<PathBuf>,
+    /// Record the salted digests of the answers of the selected days instead of checking them
+    #[arg(long)]
+    record: bool,
+}
+
+pub fn run(opt: VerifyOpt, config: &Config) -> Result<()> {
+    let file = opt.file.as_ref().unwrap_or(&config.answers_file.value);
+    let mut answers = Answers::load(file).wrap_err_with(|| format!("cannot load answers '{}'", file.display()))?;
+    let days = opt.days.resolve(config.year.value)?;
+    let mut records = Vec::default();
+
+    for day in &days {
+        let path = input::get_input_path(*day, &config.data_dir.value);
+
+        records.extend(runner::solve_day(*day, &path, &[Part::One, Part::Two], 1).map_err(report)?);
+    }
+
+    if opt.record {
+        answers.record(&records);
+        answers
+            .save(file)
+            .wrap_err_with(|| format!("cannot save answers '{}'", file.display()))?;
+
+        println!("recorded {} days into '{}'", days.len(), file.display());
+
+        return Ok(());
+    }
+
+    let verifications = answers.verify(&records);
+
+    println!(
+        "{:<10} {:<6} {:<16} {:<16}  status",
+        "day", "part", "expected", "actual"
+    );
+
+    for verification in &verifications {
+        let status = match verification.status() {
+            Status::Ok => "ok",
+            Status::Mismatch => "mismatch",
+            Status::Unrecorded => "unrecorded",
+        };
+
+        println!(
+            "{:<10} {:<6} {:<16} {:<16}  {status}",
+            verification.day,
+            verification.part,
+            verification
+                .expected
+                .as_ref()
+                .map(Expected::to_string)
+                .unwrap_or_default(),
+            verification.actual.to_string()
+        );
+    }
+
+    let mismatches = verifications
+        .iter()
+        .filter(|verification| verification.status() == Status::Mismatch)
+        .count();
+
+    if mismatches > 0 {
+        bail!("{mismatches} answers differ from the expected ones");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+use std::{env, fs, iter, thread};
+
+use advent_of_code_2023::config::Config;
+use advent_of_code_2023::examples::{self, Example};
+use advent_of_code_2023::timing::{self, Phase};
+use advent_of_code_2023::{input, Answer, Day, DayArg, Part};
+
+use clap::Args;
+use eyre::Result;
+use strum::IntoEnumIterator;
+
+use crate::new::{self, CRATE_DIR};
+use crate::{catch, format_duration};
+
+#[derive(Args)]
+pub struct WatchOpt {
+    /// Day to watch, e.g. `7`, `2023/7` or `latest`
+    day: DayArg,
+    /// Directory of example inputs to solve along with the puzzle input, defaults to the day's one within the examples
+    /// directory
+    #[arg(short, long)]
+    examples: Option<PathBuf>,
+    /// Time between two checks for changes, in milliseconds
+    #[arg(long, default_value_t = 500)]
+    interval: u64,
+}
+
+/// Outcome of a phase, failures and panics being reported as messages.
+struct Row {
+    phase: Phase,
+    outcome: Result<Option<Answer>, String>,
+    elapsed: Duration,
+}
+
+/// Loads the input at `path`, along with its expected answers when it is an example.
+fn load(path: &Path, example: bool) -> Result<Example, String> {
+    if example {
+        Example::load(path).map_err(|e| e.to_string())
+    } else {
+        input::read_input(path)
+            .map(|input| {
+                Example {
+                    expected: BTreeMap::default(),
+                    input,
+                }
+            })
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Parses `input` then solves `parts` of `day`, going as far as possible.
+fn solve(day: Day, input: &str, parts: impl IntoIterator<Item = Part>) -> Vec<Row> {
+    let (model, elapsed) = timing::measure(|| catch(|| advent_of_code_2023::parse(day, input)));
+    let model = model.and_then(|model| model.map_err(|e| e.to_string()));
+    let mut rows = vec![Row {
+        phase: Phase::Parse,
+        outcome: model.as_ref().map(|_| None).map_err(String::clone),
+        elapsed,
+    }];
+
+    if let Ok(model) = model {
+        for part in parts {
+            let (answer, elapsed) = timing::measure(|| catch(|| advent_of_code_2023::solve(day, part, &model)));
+
+            rows.push(Row {
+                phase: Phase::Solve(part),
+                outcome: answer.map(Some),
+                elapsed,
+            });
+        }
+    }
+
+    rows
+}
+
+/// Returns the modification time of the file at `path`, if any.
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+/// Returns the modification time of each of `paths`.
+fn snapshot(paths: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    paths.iter().map(|path| (path.clone(), modified(path))).collect()
+}
+
+/// Prints the answers of the puzzle input `input` then of the `examples`, checked against their expected answers.
+fn print(day: Day, input: &Path, examples: &[PathBuf]) {
+    // clears the screen and moves the cursor to its top left corner
+    print!("\x1b[2J\x1b[H");
+    println!("watching {day}, press Ctrl-C to stop\n");
+    println!("{:<16} {:<6} {:<16} {:>10}  status", "input", "phase", "answer", "time");
+
+    let inputs = iter::once((input, false)).chain(examples.iter().map(|path| (path.as_path(), true)));
+
+    for (path, example) in inputs {
+        let name = path.file_name().unwrap_or(path.as_os_str()).to_string_lossy();
+        let (example, elapsed) = timing::measure(|| load(path, example));
+        let rows = match example {
+            Ok(Example { expected, input }) if expected.is_empty() => solve(day, &input, Part::iter()),
+            Ok(Example { expected, input }) => {
+                solve(day, &input, expected.keys().copied())
+                    .into_iter()
+                    .map(|row| check(row, &expected))
+                    .collect()
+            }
+            Err(e) => {
+                vec![Row {
+                    phase: Phase::Load,
+                    outcome: Err(e),
+                    elapsed,
+                }]
+            }
+        };
+
+        for row in rows {
+            let (answer, status) = match &row.outcome {
+                Ok(answer) => (answer.as_ref().map(Answer::to_string).unwrap_or_default(), "ok"),
+                Err(message) => (String::new(), message.as_str()),
+            };
+
+            println!(
+                "{name:<16} {:<6} {answer:<16} {:>10}  {status}",
+                row.phase,
+                format_duration(row.elapsed)
+            );
+        }
+    }
+
+    let _ = io::stdout().flush();
+}
+
+/// Turns the answer of `row` into a failure unless it is the expected one.
+fn check(mut row: Row, expected: &BTreeMap<Part, String>) -> Row {
+    if let (Phase::Solve(part), Ok(Some(answer))) = (row.phase, &row.outcome) {
+        if let Some(expected) = expected.get(&part).filter(|expected| **expected != answer.to_string()) {
+            row.outcome = Err(format!("got {answer}, expected {expected}"));
+        }
+    }
+
+    row
+}
+
+/// Rebuilds the tool then replaces the current process by the rebuilt one, the solvers being compiled in it, unless
+/// the build fails.
+fn rebuild() -> Result<()> {
+    let mut cargo = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+
+    cargo.current_dir(CRATE_DIR).args(["build", "--bin", "aoc"]);
+
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+
+    if !cargo.status()?.success() {
+        println!("\nthe build failed, waiting for changes");
+
+        return Ok(());
+    }
+
+    let mut aoc = Command::new(env::current_exe()?);
+
+    aoc.args(env::args_os().skip(1));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+
+        Err(aoc.exec().into())
+    }
+
+    #[cfg(not(unix))]
+    {
+        std::process::exit(aoc.status()?.code().unwrap_or(1));
+    }
+}
+
+pub fn run(opt: WatchOpt, config: &Config) -> Result<()> {
+    let day = opt.day.resolve(config.year.value)?;
+    let source = new::module_path(day.year(), day.number());
+    let input = input::get_input_path(day, &config.data_dir.value);
+    let examples_dir = opt
+        .examples
+        .unwrap_or_else(|| examples::get_examples_dir(day, &config.examples_dir.value));
+    let examples = || examples::find_examples(&examples_dir).unwrap_or_default();
+    let inputs = || iter::once(input.clone()).chain(examples()).collect::<Vec<_>>();
+
+    let mut source_modified = modified(&source);
+    let mut inputs_modified = snapshot(&inputs());
+
+    print(day, &input, &examples());
+
+    loop {
+        thread::sleep(Duration::from_millis(opt.interval));
+
+        if modified(&source) != source_modified {
+            source_modified = modified(&source);
+            rebuild()?;
+        } else if snapshot(&inputs()) != inputs_modified {
+            inputs_modified = snapshot(&inputs());
+            print(day, &input, &examples());
+        }
+    }
+}
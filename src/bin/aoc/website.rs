@@ -0,0 +1,41 @@
+use std::fs;
+
+use advent_of_code_2023::config::Config;
+use advent_of_code_2023::website::{self, Client};
+
+use clap::Args;
+use eyre::{eyre, Result, WrapErr};
+
+/// Options of the commands talking to the website.
+#[derive(Args)]
+pub struct WebsiteOpt {
+    /// Session cookie of the logged in user [default: read from the session file]
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    /// Website serving the puzzles [default: from the configuration]
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+impl WebsiteOpt {
+    pub fn client(&self, config: &Config) -> Result<Client> {
+        let session = match &self.session {
+            Some(session) => session.clone(),
+            None => {
+                let path = config
+                    .session_file
+                    .value
+                    .as_ref()
+                    .ok_or_else(|| eyre!("no session token, pass one with --session or set a session file"))?;
+
+                fs::read_to_string(path)
+                    .wrap_err_with(|| format!("cannot read the session token from '{}'", path.display()))?
+                    .trim()
+                    .to_owned()
+            }
+        };
+        let base_url = self.base_url.as_deref().unwrap_or(&config.base_url.value);
+
+        Ok(Client::new(base_url, &session, website::DEFAULT_INTERVAL))
+    }
+}
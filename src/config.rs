@@ -0,0 +1,376 @@
+use std::path::{Path, PathBuf};
+use std::{env, fmt, fs, io};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::answers::DEFAULT_ANSWERS_PATH;
+use crate::examples::DEFAULT_EXAMPLES_DIR;
+use crate::input::DEFAULT_DATA_DIR;
+use crate::ledger::LEDGER_FILE_NAME;
+use crate::website::DEFAULT_BASE_URL;
+use crate::Day;
+
+/// Name of the project-local and user-level configuration files.
+pub const CONFIG_FILE_NAME: &str = "aoc.toml";
+
+/// Name of the session token file within the user-level configuration directory.
+pub const SESSION_FILE_NAME: &str = "session";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("cannot read configuration '{}'", .path.display())]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("invalid configuration '{}'", .path.display())]
+    Toml {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+    #[error("invalid value '{value}' of {name}")]
+    Env { name: String, value: String },
+}
+
+/// Origin of a configuration value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Default,
+    File(PathBuf),
+    /// Environment variable.
+    Env(String),
+    /// Command-line option.
+    Flag(String),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => f.write_str("default"),
+            Self::File(path) => write!(f, "file {}", path.display()),
+            Self::Env(name) => write!(f, "env {name}"),
+            Self::Flag(name) => write!(f, "flag {name}"),
+        }
+    }
+}
+
+/// Configuration value along with its origin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sourced<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+impl<T> Sourced<T> {
+    fn default(value: T) -> Self {
+        Self {
+            value,
+            source: Source::Default,
+        }
+    }
+
+    /// Overrides the value by `value` coming from `source`, if any.
+    fn set(&mut self, value: Option<T>, source: Source) {
+        if let Some(value) = value {
+            *self = Self { value, source };
+        }
+    }
+}
+
+/// Values set by a configuration file, the environment or the command line, e.g. `data-dir = "data"`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Layer {
+    /// File holding the session token of the logged in user.
+    pub session_file: Option<PathBuf>,
+    /// File recording the submitted answers and their verdicts.
+    pub ledger_file: Option<PathBuf>,
+    /// Directory holding the puzzle inputs.
+    pub data_dir: Option<PathBuf>,
+    /// Directory holding the example inputs, one directory per day.
+    pub examples_dir: Option<PathBuf>,
+    pub answers_file: Option<PathBuf>,
+    /// Output format of the solved puzzles.
+    pub format: Option<String>,
+    pub year: Option<u16>,
+    /// Website serving the puzzles.
+    pub base_url: Option<String>,
+}
+
+impl Layer {
+    /// Loads the layer stored at `path`, relative paths being resolved against its directory, or `None` if absent.
+    pub fn load(path: &Path) -> Result<Option<Self>, Error> {
+        let s = match fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => {
+                return Err(Error::Read {
+                    path: path.to_owned(),
+                    source,
+                })
+            }
+        };
+        let mut layer = toml::from_str::<Self>(&s).map_err(|source| {
+            Error::Toml {
+                path: path.to_owned(),
+                source,
+            }
+        })?;
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let home = env::var_os("HOME").map(PathBuf::from);
+
+        for path in [
+            &mut layer.session_file,
+            &mut layer.ledger_file,
+            &mut layer.data_dir,
+            &mut layer.examples_dir,
+            &mut layer.answers_file,
+        ] {
+            *path = path.take().map(|path| resolve(dir, &path, home.as_deref()));
+        }
+
+        Ok(Some(layer))
+    }
+
+    /// Reads the layer from the `AOC_*` environment variables given by `var`, e.g. `AOC_DATA_DIR`.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Result<Self, Error> {
+        let var = |key| var(&env_name(key));
+
+        Ok(Self {
+            session_file: var("session-file").map(PathBuf::from),
+            ledger_file: var("ledger-file").map(PathBuf::from),
+            data_dir: var("data-dir").map(PathBuf::from),
+            examples_dir: var("examples-dir").map(PathBuf::from),
+            answers_file: var("answers-file").map(PathBuf::from),
+            format: var("format"),
+            year: var("year")
+                .map(|value| {
+                    value.parse().map_err(|_| {
+                        Error::Env {
+                            name: env_name("year"),
+                            value,
+                        }
+                    })
+                })
+                .transpose()?,
+            base_url: var("base-url"),
+        })
+    }
+}
+
+/// Returns the environment variable overriding `key`.
+fn env_name(key: &str) -> String {
+    format!("AOC_{}", key.replace('-', "_").to_uppercase())
+}
+
+/// Resolves `path` read from a file in `dir`, a leading `~` standing for `home`.
+fn resolve(dir: &Path, path: &Path, home: Option<&Path>) -> PathBuf {
+    match (path.strip_prefix("~"), home) {
+        (Ok(path), Some(home)) => home.join(path),
+        _ => dir.join(path),
+    }
+}
+
+/// Returns the user-level configuration directory, `$XDG_CONFIG_HOME/aoc` or `~/.config/aoc`.
+pub fn user_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .map(|dir| dir.join("aoc"))
+}
+
+/// Effective configuration, merged from the defaults, the project-local file, the user-level file and the
+/// environment, each overriding the previous ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session_file: Sourced<Option<PathBuf>>,
+    pub ledger_file: Sourced<Option<PathBuf>>,
+    pub data_dir: Sourced<PathBuf>,
+    pub examples_dir: Sourced<PathBuf>,
+    pub answers_file: Sourced<PathBuf>,
+    pub format: Sourced<String>,
+    pub year: Sourced<u16>,
+    pub base_url: Sourced<String>,
+}
+
+impl Config {
+    /// Returns the default configuration, the session token being read from and the guesses being recorded into
+    /// `user_dir` if any, and the year being the latest one having registered days.
+    pub fn new(user_dir: Option<&Path>) -> Self {
+        Self {
+            session_file: Sourced::default(user_dir.map(|dir| dir.join(SESSION_FILE_NAME))),
+            ledger_file: Sourced::default(user_dir.map(|dir| dir.join(LEDGER_FILE_NAME))),
+            data_dir: Sourced::default(PathBuf::from(DEFAULT_DATA_DIR)),
+            examples_dir: Sourced::default(PathBuf::from(DEFAULT_EXAMPLES_DIR)),
+            answers_file: Sourced::default(PathBuf::from(DEFAULT_ANSWERS_PATH)),
+            format: Sourced::default("text".to_owned()),
+            year: Sourced::default(Day::years().last().expect("days are registered")),
+            base_url: Sourced::default(DEFAULT_BASE_URL.to_owned()),
+        }
+    }
+
+    /// Loads the configuration, the project-local file being the closest `aoc.toml` from `current_dir` up.
+    pub fn load(
+        current_dir: &Path,
+        user_dir: Option<&Path>,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, Error> {
+        let mut config = Self::new(user_dir);
+        let project_file = current_dir
+            .ancestors()
+            .map(|dir| dir.join(CONFIG_FILE_NAME))
+            .find(|path| path.is_file());
+
+        for path in project_file
+            .into_iter()
+            .chain(user_dir.map(|dir| dir.join(CONFIG_FILE_NAME)))
+        {
+            if let Some(layer) = Layer::load(&path)? {
+                config.merge(layer, |_| Source::File(path.clone()));
+            }
+        }
+
+        config.merge(Layer::from_env(var)?, |key| Source::Env(env_name(key)));
+
+        Ok(config)
+    }
+
+    /// Overrides the values set by `layer`, `source` giving the origin of each key.
+    pub fn merge(&mut self, layer: Layer, source: impl Fn(&str) -> Source) {
+        self.session_file
+            .set(layer.session_file.map(Some), source("session-file"));
+        self.ledger_file.set(layer.ledger_file.map(Some), source("ledger-file"));
+        self.data_dir.set(layer.data_dir, source("data-dir"));
+        self.examples_dir.set(layer.examples_dir, source("examples-dir"));
+        self.answers_file.set(layer.answers_file, source("answers-file"));
+        self.format.set(layer.format, source("format"));
+        self.year.set(layer.year, source("year"));
+        self.base_url.set(layer.base_url, source("base-url"));
+    }
+
+    /// Returns the key, value and origin of each setting, unset values being `None`.
+    pub fn entries(&self) -> [(&'static str, Option<String>, &Source); 8] {
+        let path = |path: &Path| Some(path.display().to_string());
+
+        [
+            (
+                "session-file",
+                self.session_file.value.as_deref().and_then(path),
+                &self.session_file.source,
+            ),
+            (
+                "ledger-file",
+                self.ledger_file.value.as_deref().and_then(path),
+                &self.ledger_file.source,
+            ),
+            ("data-dir", path(&self.data_dir.value), &self.data_dir.source),
+            (
+                "examples-dir",
+                path(&self.examples_dir.value),
+                &self.examples_dir.source,
+            ),
+            (
+                "answers-file",
+                path(&self.answers_file.value),
+                &self.answers_file.source,
+            ),
+            ("format", Some(self.format.value.clone()), &self.format.source),
+            ("year", Some(self.year.value.to_string()), &self.year.source),
+            ("base-url", Some(self.base_url.value.clone()), &self.base_url.source),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_merges_layers_in_order() {
+        let project_dir = tempfile::tempdir().unwrap();
+        let user_dir = tempfile::tempdir().unwrap();
+        let current_dir = project_dir.path().join("src");
+        let project_file = project_dir.path().join(CONFIG_FILE_NAME);
+        let user_file = user_dir.path().join(CONFIG_FILE_NAME);
+
+        fs::create_dir(&current_dir).unwrap();
+        fs::write(&project_file, "data-dir = \"inputs\"\nformat = \"json\"\n").unwrap();
+        fs::write(&user_file, "format = \"csv\"\n").unwrap();
+
+        let config = Config::load(&current_dir, Some(user_dir.path()), |name| {
+            (name == "AOC_BASE_URL").then(|| "http://127.0.0.1".to_owned())
+        })
+        .unwrap();
+
+        assert_eq!(
+            Sourced {
+                value: Some(user_dir.path().join(SESSION_FILE_NAME)),
+                source: Source::Default
+            },
+            config.session_file
+        );
+        assert_eq!(
+            Sourced {
+                value: project_dir.path().join("inputs"),
+                source: Source::File(project_file)
+            },
+            config.data_dir
+        );
+        assert_eq!(
+            Sourced {
+                value: "csv".to_owned(),
+                source: Source::File(user_file)
+            },
+            config.format
+        );
+        assert_eq!(
+            Sourced {
+                value: "http://127.0.0.1".to_owned(),
+                source: Source::Env("AOC_BASE_URL".to_owned())
+            },
+            config.base_url
+        );
+    }
+
+    #[test]
+    fn it_expands_the_home_directory() {
+        let dir = Path::new("/project");
+        let home = Path::new("/home/elf");
+
+        assert_eq!(
+            PathBuf::from("/home/elf/.config/aoc/session"),
+            resolve(dir, Path::new("~/.config/aoc/session"), Some(home))
+        );
+        assert_eq!(
+            PathBuf::from("/project/data"),
+            resolve(dir, Path::new("data"), Some(home))
+        );
+        assert_eq!(
+            PathBuf::from("/project/~user"),
+            resolve(dir, Path::new("~user"), Some(home))
+        );
+        assert_eq!(
+            PathBuf::from("/project/~/session"),
+            resolve(dir, Path::new("~/session"), None)
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_settings() {
+        let dir = tempfile::tempdir().unwrap();
+
+        fs::write(dir.path().join(CONFIG_FILE_NAME), "data_dir = \"inputs\"\n").unwrap();
+
+        assert!(matches!(
+            Config::load(dir.path(), None, |_| None),
+            Err(Error::Toml { .. })
+        ));
+        assert!(matches!(
+            Layer::from_env(|name| (name == "AOC_YEAR").then(|| "last".to_owned())),
+            Err(Error::Env { .. })
+        ));
+    }
+}
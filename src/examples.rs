@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{fmt, fs, io};
+
+use thiserror::Error;
+
+use crate::{Day, Part};
+
+/// Directory holding the checked-in example inputs.
+pub const DEFAULT_EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("invalid header line '{0}', expected e.g. `part1: 142`")]
+    InvalidHeader(String),
+    #[error("missing expected answers, the header must give at least one part followed by an empty line")]
+    MissingHeader,
+}
+
+/// Example input along with the answers expected from it, written as a header such as
+///
+/// ```text
+/// part1: 142
+/// part2: 281
+///
+/// 1abc2
+/// ```
+///
+/// either part being optional.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub expected: BTreeMap<Part, String>,
+    pub input: String,
+}
+
+impl Example {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        fs::read_to_string(path)?.parse()
+    }
+}
+
+impl FromStr for Example {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (header, input) = s.split_once("\n\n").ok_or(Error::MissingHeader)?;
+        let expected = header
+            .lines()
+            .map(|line| {
+                let (part, answer) = line
+                    .split_once(':')
+                    .ok_or_else(|| Error::InvalidHeader(line.to_owned()))?;
+                let part = match part.trim() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(Error::InvalidHeader(line.to_owned())),
+                };
+
+                Ok((part, answer.trim().to_owned()))
+            })
+            .collect::<Result<BTreeMap<_, _>, _>>()?;
+
+        if expected.is_empty() {
+            return Err(Error::MissingHeader);
+        }
+
+        Ok(Self {
+            expected,
+            input: input.to_owned(),
+        })
+    }
+}
+
+impl fmt::Display for Example {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (part, answer) in &self.expected {
+            writeln!(f, "part{part}: {answer}")?;
+        }
+
+        writeln!(f)?;
+        f.write_str(&self.input)
+    }
+}
+
+/// Returns the directory holding the examples of `day` within `examples_dir`, e.g. `2023/day07`.
+pub fn get_examples_dir(day: Day, examples_dir: &Path) -> PathBuf {
+    get_examples_dir_by_number(day.year(), day.number(), examples_dir)
+}
+
+/// Returns the directory holding the examples of the `n`th day of `year` within `examples_dir`, whether the day is
+/// solved or not.
+pub fn get_examples_dir_by_number(year: u16, n: u8, examples_dir: &Path) -> PathBuf {
+    examples_dir.join(year.to_string()).join(format!("day{n:02}"))
+}
+
+/// Returns the paths of the `*.txt` examples found in `dir` in order, an absent directory holding none.
+pub fn find_examples(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::default()),
+        Err(e) => return Err(e),
+    };
+    let mut paths = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .filter(|path| {
+            path.as_ref().map_or(true, |path| {
+                path.extension().is_some_and(|extension| extension == "txt")
+            })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    paths.sort();
+
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_expected_answers() {
+        let example = "part2: 281\n\ntwo1nine\n\neightwothree\n".parse::<Example>().unwrap();
+
+        assert_eq!(BTreeMap::from([(Part::Two, "281".to_owned())]), example.expected);
+        assert_eq!("two1nine\n\neightwothree\n", example.input);
+        assert_eq!(example, example.to_string().parse().unwrap());
+    }
+
+    #[test]
+    fn it_rejects_missing_or_invalid_headers() {
+        assert!(matches!(
+            "1abc2\npqr3stu8vwx\n".parse::<Example>(),
+            Err(Error::MissingHeader)
+        ));
+        assert!(matches!("\n\n1abc2\n".parse::<Example>(), Err(Error::MissingHeader)));
+        assert!(matches!(
+            "part3: 1\n\n1abc2\n".parse::<Example>(),
+            Err(Error::InvalidHeader(line)) if line == "part3: 1"
+        ));
+    }
+}
@@ -0,0 +1,197 @@
+//! Minimal reader of the pages served by the website, which are well-formed enough to be read tag by tag.
+
+/// Tag or text of a page, names being lowercase and text being unescaped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    Start { name: String, attrs: Vec<(String, String)> },
+    End(String),
+    Text(String),
+}
+
+impl Token {
+    /// Returns the value of the attribute `key` of a start tag.
+    pub fn attr(&self, key: &str) -> Option<&str> {
+        match self {
+            Self::Start { attrs, .. } => attrs.iter().find(|(k, _)| k == key).map(|(_, value)| value.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn is_start(&self, tag: &str) -> bool {
+        matches!(self, Self::Start { name, .. } if name == tag)
+    }
+
+    pub fn is_end(&self, tag: &str) -> bool {
+        matches!(self, Self::End(name) if name == tag)
+    }
+}
+
+/// Splits `html` into tags and texts, skipping comments, doctypes, scripts and styles.
+pub fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = Vec::default();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(unescape(rest)));
+            break;
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(unescape(&rest[..start])));
+        }
+
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.split_once("-->").map_or("", |(_, rest)| rest);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            tokens.push(Token::Text(unescape(rest)));
+            break;
+        };
+        let tag = &rest[1..end];
+
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::End(name.trim().to_ascii_lowercase()));
+            continue;
+        }
+
+        let tag = tag.strip_suffix('/').unwrap_or(tag);
+        let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+        let name = name.to_ascii_lowercase();
+
+        // their content is not text
+        if name == "script" || name == "style" {
+            let close = format!("</{name}");
+
+            rest = rest.find(&close).map_or("", |end| &rest[end..]);
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+
+        tokens.push(Token::Start {
+            name,
+            attrs: parse_attrs(attrs),
+        });
+    }
+
+    tokens
+}
+
+/// Parses attributes like `class="day-desc" id=part2 hidden`.
+fn parse_attrs(mut s: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::default();
+
+    loop {
+        s = s.trim_start();
+
+        if s.is_empty() {
+            return attrs;
+        }
+
+        let end = s.find(|c: char| c == '=' || c.is_whitespace()).unwrap_or(s.len());
+        let key = s[..end].to_ascii_lowercase();
+
+        s = s[end..].trim_start();
+
+        let value = match s.strip_prefix('=').map(str::trim_start) {
+            Some(value) => {
+                let (value, rest) = match value.chars().next() {
+                    Some(quote @ ('"' | '\'')) => value[1..].split_once(quote).unwrap_or((&value[1..], "")),
+                    _ => value.split_at(value.find(char::is_whitespace).unwrap_or(value.len())),
+                };
+
+                s = rest;
+                unescape(value)
+            }
+            None => String::new(),
+        };
+
+        attrs.push((key, value));
+    }
+}
+
+/// Replaces the character references of `s`, e.g. `&lt;` or `&#39;`, leaving unknown ones as is.
+pub fn unescape(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let reference = rest[1..].find(';').map(|end| &rest[1..end + 1]);
+        let c = reference.and_then(|reference| {
+            match reference {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                _ => {
+                    let code = reference.strip_prefix('#')?;
+                    let code = match code.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => code.parse().ok()?,
+                    };
+
+                    char::from_u32(code)
+                }
+            }
+        });
+
+        match (c, reference) {
+            (Some(c), Some(reference)) => {
+                unescaped.push(c);
+                rest = &rest[reference.len() + 2..];
+            }
+            _ => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    unescaped.push_str(rest);
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start(name: &str, attrs: &[(&str, &str)]) -> Token {
+        Token::Start {
+            name: name.to_owned(),
+            attrs: attrs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+        }
+    }
+
+    #[test]
+    fn it_splits_tags_and_texts() {
+        let html = "<!DOCTYPE html><!-- hello --><script>if (a<b) {}</script><article class=\"day-desc\" hidden>\
+                    <a href='/2023'>1 &lt; 2 &amp;&amp; &#x41;&#66; &unknown;</a><br/></article>";
+
+        assert_eq!(
+            vec![
+                start("article", &[("class", "day-desc"), ("hidden", "")]),
+                start("a", &[("href", "/2023")]),
+                Token::Text("1 < 2 && AB &unknown;".to_owned()),
+                Token::End("a".to_owned()),
+                start("br", &[]),
+                Token::End("article".to_owned()),
+            ],
+            tokenize(html)
+        );
+    }
+}
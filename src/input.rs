@@ -0,0 +1,35 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::Day;
+
+/// Directory holding the checked-in puzzle inputs.
+pub const DEFAULT_DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+/// Path standing for the standard input.
+pub const STDIN_PATH: &str = "-";
+
+/// Returns the path of the puzzle input of `day` within `data_dir`.
+pub fn get_input_path(day: Day, data_dir: &Path) -> PathBuf {
+    get_input_path_by_number(day.year(), day.number(), data_dir)
+}
+
+/// Returns the path of the puzzle input of the `n`th day of `year` within `data_dir`, whether the day is solved or not,
+/// e.g. `2023/day07.txt`.
+pub fn get_input_path_by_number(year: u16, n: u8, data_dir: &Path) -> PathBuf {
+    data_dir.join(year.to_string()).join(format!("day{n:02}.txt"))
+}
+
+/// Reads the puzzle input located at `path`, or from the standard input when `path` is `-`.
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new(STDIN_PATH) {
+        let mut input = String::new();
+
+        io::stdin().read_to_string(&mut input)?;
+
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+    }
+}
@@ -0,0 +1,177 @@
+use std::path::Path;
+use std::{fs, io};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::website::Verdict;
+use crate::{Answer, Day, Part};
+
+/// Name of the ledger file within the user-level configuration directory.
+pub const LEDGER_FILE_NAME: &str = "guesses.json";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+/// Reason not to submit an answer, given the previous guesses.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum Rejection {
+    #[error("the part is already solved with '{0}'")]
+    AlreadySolved(String),
+    #[error("the answer was already judged wrong")]
+    KnownWrong,
+    #[error("the answer is not below '{0}', which is too high")]
+    NotBelow(String),
+    #[error("the answer is not above '{0}', which is too low")]
+    NotAbove(String),
+}
+
+/// Answer submitted to the website along with its judgement.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Submitted guesses of every day.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ledger(Vec<Guess>);
+
+impl Ledger {
+    /// Loads the ledger stored at `path`, an absent file standing for no guess.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(s) => Ok(serde_json::from_str(&s)?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let mut s = serde_json::to_string_pretty(self)?;
+
+        s.push('\n');
+        fs::write(path, s)?;
+
+        Ok(())
+    }
+
+    fn guesses(&self, day: Day, part: Part) -> impl Iterator<Item = &Guess> {
+        self.0
+            .iter()
+            .filter(move |guess| guess.year == day.year() && guess.day == day.number() && guess.part == part.number())
+    }
+
+    /// Returns whether `part` of `day` was judged solved, earning its star.
+    pub fn is_solved(&self, day: Day, part: Part) -> bool {
+        self.guesses(day, part).any(|guess| guess.verdict == Verdict::Correct)
+    }
+
+    /// Checks that `answer` is worth submitting, neither already judged nor contradicting a judged bound.
+    pub fn check(&self, day: Day, part: Part, answer: &Answer) -> Result<(), Rejection> {
+        let answer = answer.to_string();
+        let number = answer.parse::<i128>().ok();
+
+        for guess in self.guesses(day, part) {
+            let bound = guess.answer.parse::<i128>().ok();
+
+            match (guess.verdict, number.zip(bound)) {
+                (Verdict::Wait(_) | Verdict::WrongLevel, _) => {}
+                (Verdict::Correct, _) => return Err(Rejection::AlreadySolved(guess.answer.clone())),
+                (_, _) if guess.answer == answer => return Err(Rejection::KnownWrong),
+                (Verdict::TooHigh, Some((number, bound))) if number >= bound => {
+                    return Err(Rejection::NotBelow(guess.answer.clone()));
+                }
+                (Verdict::TooLow, Some((number, bound))) if number <= bound => {
+                    return Err(Rejection::NotAbove(guess.answer.clone()));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records the submission of `answer`, even one left unjudged.
+    pub fn record(&mut self, day: Day, part: Part, answer: &Answer, verdict: Verdict) {
+        self.0.push(Guess {
+            year: day.year(),
+            day: day.number(),
+            part: part.number(),
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_known_and_out_of_bounds_answers() {
+        let day = Day::from_number(2023, 1).unwrap();
+        let mut ledger = Ledger::default();
+
+        ledger.record(day, Part::One, &100.into(), Verdict::TooHigh);
+        ledger.record(day, Part::One, &10.into(), Verdict::TooLow);
+        ledger.record(day, Part::One, &"abc".into(), Verdict::Wrong);
+
+        assert_eq!(
+            Err(Rejection::NotBelow("100".to_owned())),
+            ledger.check(day, Part::One, &120.into())
+        );
+        assert_eq!(
+            Err(Rejection::NotAbove("10".to_owned())),
+            ledger.check(day, Part::One, &5.into())
+        );
+        assert_eq!(Err(Rejection::KnownWrong), ledger.check(day, Part::One, &"abc".into()));
+        assert_eq!(Ok(()), ledger.check(day, Part::One, &50.into()));
+        assert_eq!(Ok(()), ledger.check(day, Part::Two, &120.into()));
+
+        assert!(!ledger.is_solved(day, Part::One));
+
+        ledger.record(day, Part::One, &50.into(), Verdict::Correct);
+
+        assert_eq!(
+            Err(Rejection::AlreadySolved("50".to_owned())),
+            ledger.check(day, Part::One, &51.into())
+        );
+        assert!(ledger.is_solved(day, Part::One));
+        assert!(!ledger.is_solved(day, Part::Two));
+    }
+
+    #[test]
+    fn it_ignores_unjudged_submissions() {
+        let day = Day::from_number(2023, 1).unwrap();
+        let mut ledger = Ledger::default();
+
+        ledger.record(
+            day,
+            Part::One,
+            &42.into(),
+            Verdict::Wait(std::time::Duration::from_secs(30)),
+        );
+
+        assert_eq!(Ok(()), ledger.check(day, Part::One, &42.into()));
+    }
+
+    #[test]
+    fn it_earns_stars_only_from_correct_answers() {
+        let day = Day::from_number(2023, 1).unwrap();
+        let mut ledger = Ledger::default();
+
+        ledger.record(day, Part::Two, &42.into(), Verdict::WrongLevel);
+        ledger.record(day, Part::Two, &43.into(), Verdict::Wrong);
+
+        assert!(!ledger.is_solved(day, Part::Two));
+    }
+}
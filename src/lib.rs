@@ -1,35 +1,178 @@
+use std::fmt;
+use std::str::FromStr;
+
 use clap::ValueEnum;
+use itertools::Itertools;
+use strum::{Display, EnumIter};
+use thiserror::Error;
+
+pub use answer::Answer;
+pub use parser::ParseError;
+pub use registry::Model;
+pub use selection::{DayArg, DaySelection};
+
+mod answer;
+pub mod answers;
+pub mod baseline;
+pub mod config;
+pub mod examples;
+mod html;
+pub mod input;
+pub mod ledger;
+pub mod parser;
+pub mod puzzle;
+pub mod registry;
+pub mod runner;
+pub mod selection;
+mod slow;
+pub mod timing;
+pub mod website;
+mod years;
+
+/// Day of the month of an event having a registered parser.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Day {
+    year: u16,
+    n: u8,
+}
+
+impl Day {
+    /// Returns the registered days in order, year by year.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        registry::parsers()
+            .map(|parser| {
+                Self {
+                    year: parser.year,
+                    n: parser.day,
+                }
+            })
+            .dedup()
+    }
+
+    /// Returns the years having registered days in order.
+    pub fn years() -> impl Iterator<Item = u16> {
+        Self::iter().map(Self::year).dedup()
+    }
+
+    pub fn year(self) -> u16 {
+        self.year
+    }
+
+    /// Returns the day of the month.
+    pub fn number(self) -> u8 {
+        self.n
+    }
+
+    /// Returns the name of the day within its year, e.g. `day07`.
+    pub fn name(self) -> String {
+        format!("day{:02}", self.n)
+    }
+
+    pub fn from_number(year: u16, n: u8) -> Option<Self> {
+        Self::iter().find(|day| day.year == year && day.n == n)
+    }
+
+    /// Returns why the day takes minutes to solve, if it does.
+    pub fn slowness(self) -> Option<&'static str> {
+        slow::SLOW_DAYS
+            .iter()
+            .find(|(year, n, _)| (*year, *n) == (self.year, self.n))
+            .map(|(_, _, reason)| *reason)
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&format!("{}/{}", self.year, self.name()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown day '{0}'")]
+pub struct UnknownDayError(String);
+
+impl FromStr for Day {
+    type Err = UnknownDayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::iter()
+            .find(|day| day.to_string() == s)
+            .ok_or_else(|| UnknownDayError(s.to_owned()))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Display, EnumIter, ValueEnum)]
+pub enum Part {
+    #[strum(serialize = "1")]
+    #[value(name = "1")]
+    One,
+    #[strum(serialize = "2")]
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+/// Parses the puzzle input of `day` into a model shared by both parts.
+pub fn parse(day: Day, input: &str) -> Result<Model, ParseError> {
+    let parser = registry::parsers()
+        .find(|parser| parser.year == day.year && parser.day == day.n)
+        .expect("days have a registered parser");
+
+    (parser.parse)(input)
+}
+
+/// Solves `part` of `day` from a model returned by [`parse`] for the same day, with its main solver.
+pub fn solve(day: Day, part: Part, model: &Model) -> Answer {
+    let solution =
+        registry::solution(day, part).unwrap_or_else(|| panic!("no solver is registered for {day} part {part}"));
+
+    (solution.solve)(model)
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_every_day_from_its_name() {
+        for day in Day::iter() {
+            assert_eq!(Ok(day), day.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn it_numbers_days_in_order() {
+        for (previous, day) in Day::iter().tuple_windows() {
+            assert!(previous < day);
+        }
+
+        for day in Day::iter() {
+            assert_eq!(Some(day), Day::from_number(day.year(), day.number()));
+        }
+
+        assert_eq!(None, Day::from_number(2023, 0));
+        assert_eq!(None, Day::from_number(2014, 1));
+    }
 
-use days::*;
-
-mod days;
-
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
-pub enum Day {
-    Day01,
-    Day02,
-    Day03,
-    Day04,
-    Day05,
-    Day06,
-    Day07,
-}
-
-pub fn solve(day: Day, second_part: bool) {
-    match (day, second_part) {
-        (Day::Day01, false) => day01::solve_part1(),
-        (Day::Day01, true) => day01::solve_part2(),
-        (Day::Day02, false) => day02::solve_part1(),
-        (Day::Day02, true) => day02::solve_part2(),
-        (Day::Day03, false) => day03::solve_part1(),
-        (Day::Day03, true) => day03::solve_part2(),
-        (Day::Day04, false) => day04::solve_part1(),
-        (Day::Day04, true) => day04::solve_part2(),
-        (Day::Day05, false) => day05::solve_part1(),
-        (Day::Day05, true) => day05::solve_part2(),
-        (Day::Day06, false) => day06::solve_part1(),
-        (Day::Day06, true) => day06::solve_part2(),
-        (Day::Day07, false) => day07::solve_part1(),
-        (Day::Day07, true) => day07::solve_part2(),
+    #[test]
+    fn it_registers_both_parts_of_every_day() {
+        for day in Day::iter() {
+            for part in Part::iter() {
+                assert!(
+                    registry::solution(day, part).is_some(),
+                    "no solver is registered for {day} part {part}"
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,402 @@
+use std::iter::Enumerate;
+use std::ops::Range;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// Error raised when a puzzle input is malformed.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("line {line}, column {column}: expected {expected}", column = .columns.start + 1)]
+pub struct ParseError {
+    /// Line of the offending token, starting at 1.
+    pub line: usize,
+    /// Byte span of the offending token within its line.
+    pub columns: Range<usize>,
+    /// Description of the token expected instead.
+    pub expected: String,
+}
+
+/// Lines of a puzzle input, yielded as [`Cursor`]s.
+pub struct Lines<'a> {
+    lines: Enumerate<std::str::Lines<'a>>,
+    line: usize,
+}
+
+impl<'a> Lines<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            lines: input.lines().enumerate(),
+            line: 0,
+        }
+    }
+
+    /// Returns the next line, failing with `expected` once the input is exhausted.
+    pub fn expect(&mut self, expected: &str) -> Result<Cursor<'a>, ParseError> {
+        self.next().ok_or_else(|| {
+            ParseError {
+                line: self.line + 1,
+                columns: 0..0,
+                expected: expected.to_owned(),
+            }
+        })
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Cursor<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lines.next().map(|(i, text)| {
+            self.line = i + 1;
+
+            Cursor::new(self.line, text)
+        })
+    }
+}
+
+/// Reader over a single input line keeping track of the position of the tokens it yields.
+///
+/// Every reading method skips the leading whitespaces.
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    line: usize,
+    text: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: usize, text: &'a str) -> Self {
+        Self {
+            line,
+            text,
+            start: 0,
+            end: text.len(),
+        }
+    }
+
+    /// Returns the not yet consumed part of the line.
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.start..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().trim().is_empty()
+    }
+
+    /// Builds an error spanning `token`, which must have been read from this cursor.
+    pub fn error_at(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let start = token.as_ptr() as usize - self.text.as_ptr() as usize;
+
+        debug_assert!(start + token.len() <= self.text.len(), "token read from another line");
+
+        ParseError {
+            line: self.line,
+            columns: start..(start + token.len()),
+            expected: expected.into(),
+        }
+    }
+
+    /// Builds an error spanning the next whitespace-delimited token.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let rest = self.rest().trim_start();
+        let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+
+        self.error_at(&rest[..len], expected)
+    }
+
+    /// Consumes `tag`.
+    pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        self.skip_whitespaces();
+
+        if self.rest().starts_with(tag) {
+            self.start += tag.len();
+
+            Ok(())
+        } else {
+            Err(self.error(format!("`{tag}`")))
+        }
+    }
+
+    /// Consumes the next whitespace-delimited token.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        self.skip_whitespaces();
+
+        let rest = self.rest();
+        let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+
+        if len == 0 {
+            return Err(self.error("word"));
+        }
+
+        self.start += len;
+
+        Ok(&rest[..len])
+    }
+
+    /// Consumes the next whitespace-delimited token and parses it as `expected`.
+    pub fn parse<T: FromStr>(&mut self, expected: &str) -> Result<T, ParseError> {
+        let word = self.word().map_err(|_| self.error(expected))?;
+
+        word.parse().map_err(|_| self.error_at(word, expected))
+    }
+
+    /// Consumes the next signed decimal integer.
+    pub fn integer<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespaces();
+
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with(['-', '+']));
+        let len = sign
+            + rest[sign..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len() - sign);
+
+        if len == sign {
+            return Err(self.error("integer"));
+        }
+
+        let integer = rest[..len]
+            .parse()
+            .map_err(|_| self.error_at(&rest[..len], "integer"))?;
+
+        self.start += len;
+
+        Ok(integer)
+    }
+
+    /// Consumes integers up to the end of the line.
+    pub fn integers<T: FromStr>(&mut self) -> Result<Vec<T>, ParseError> {
+        let mut integers = Vec::default();
+
+        while !self.is_empty() {
+            integers.push(self.integer()?);
+        }
+
+        Ok(integers)
+    }
+
+    /// Consumes the line up to and including `delimiter`, returning a cursor over the part preceding it.
+    pub fn until(&mut self, delimiter: &str) -> Result<Cursor<'a>, ParseError> {
+        match self.rest().find(delimiter) {
+            Some(i) => {
+                let head = Self {
+                    end: self.start + i,
+                    ..self.clone()
+                };
+
+                self.start += i + delimiter.len();
+
+                Ok(head)
+            }
+            None => Err(self.error_at(&self.rest()[self.rest().len()..], format!("`{delimiter}`"))),
+        }
+    }
+
+    /// Consumes the next whitespace-delimited token, returning a cursor over it.
+    pub fn token(&mut self) -> Result<Cursor<'a>, ParseError> {
+        let word = self.word()?;
+
+        Ok(self.span(word))
+    }
+
+    /// Consumes the rest of the line, returning a cursor over it.
+    pub fn take(&mut self) -> Cursor<'a> {
+        let rest = self.clone();
+
+        self.start = self.end;
+
+        rest
+    }
+
+    /// Consumes the next non-whitespace character.
+    pub fn char(&mut self) -> Result<char, ParseError> {
+        self.skip_whitespaces();
+
+        let c = self.rest().chars().next().ok_or_else(|| self.error("character"))?;
+
+        self.start += c.len_utf8();
+
+        Ok(c)
+    }
+
+    /// Splits the rest of the line into cursors separated by `delimiter`.
+    pub fn split(self, delimiter: char) -> impl Iterator<Item = Cursor<'a>> {
+        self.rest().split(delimiter).map(move |s| self.span(s))
+    }
+
+    /// Ensures the line has been entirely consumed.
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+
+    /// Returns a cursor over `token`, which must have been read from this cursor.
+    fn span(&self, token: &str) -> Cursor<'a> {
+        let start = token.as_ptr() as usize - self.text.as_ptr() as usize;
+
+        Self {
+            start,
+            end: start + token.len(),
+            ..self.clone()
+        }
+    }
+
+    fn skip_whitespaces(&mut self) {
+        let rest = self.rest();
+
+        self.start += rest.len() - rest.trim_start().len();
+    }
+}
+
+/// Value read from the start of a [`Cursor`], derived with `#[derive(FromInput)]` for structs declaring the pattern of
+/// their textual representation, e.g. `#[input("Card {id}: {winning} | {scratched}")]`, and for enums implementing
+/// [`FromStr`].
+///
+/// Fields are read up to the literal following them in the pattern, a whitespace standing for the next
+/// whitespace-delimited token, and collections whose items are separated by something else than whitespaces declare
+/// it after their name, e.g. `{sets:;}`. Text matched by `{_}` is ignored.
+pub trait FromInput: Sized {
+    fn read(cursor: &mut Cursor) -> Result<Self, ParseError>;
+
+    /// Reads a value spanning the whole line.
+    fn from_line(mut cursor: Cursor) -> Result<Self, ParseError> {
+        let value = Self::read(&mut cursor)?;
+
+        cursor.end()?;
+
+        Ok(value)
+    }
+}
+
+macro_rules! impl_from_input_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl FromInput for $t {
+                fn read(cursor: &mut Cursor) -> Result<Self, ParseError> {
+                    cursor.integer()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_input_for_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl FromInput for char {
+    fn read(cursor: &mut Cursor) -> Result<Self, ParseError> {
+        cursor.char()
+    }
+}
+
+impl FromInput for String {
+    fn read(cursor: &mut Cursor) -> Result<Self, ParseError> {
+        cursor.word().map(str::to_owned)
+    }
+}
+
+/// Reads whitespace-separated items up to the end of the cursor.
+impl<T: FromInput> FromInput for Vec<T> {
+    fn read(cursor: &mut Cursor) -> Result<Self, ParseError> {
+        let mut items = Vec::default();
+
+        while !cursor.is_empty() {
+            items.push(T::read(cursor)?);
+        }
+
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_macros::FromInput;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq, FromInput)]
+    #[input("Game {id}: {sets:;}")]
+    struct Game {
+        id: usize,
+        sets: Vec<Vec<u32>>,
+    }
+
+    #[test]
+    fn it_reads_tokens() {
+        let mut cursor = Cursor::new(1, "Card 3:  1 21 | 69 -82");
+
+        assert_eq!(Ok(()), cursor.tag("Card"));
+        assert_eq!(Ok(3), cursor.integer::<usize>());
+        assert_eq!(Ok(()), cursor.tag(":"));
+        assert_eq!(Ok(vec![1, 21]), cursor.until("|").and_then(|mut c| c.integers::<i32>()));
+        assert_eq!(Ok(vec![69, -82]), cursor.integers::<i32>());
+        assert_eq!(Ok(()), cursor.end());
+    }
+
+    #[test]
+    fn it_locates_the_offending_token() {
+        let mut cursor = Cursor::new(4, "Game 12: 3 blue, 4 grean");
+        let error = cursor
+            .tag("Game")
+            .and_then(|_| cursor.integer::<usize>())
+            .and_then(|_| cursor.tag(";"));
+
+        assert_eq!(
+            Err(ParseError {
+                line: 4,
+                columns: 7..8,
+                expected: "`;`".to_owned(),
+            }),
+            error
+        );
+
+        let mut cube = cursor.clone().split(',').nth(1).unwrap();
+        let error = cube.integer::<usize>().and_then(|_| cube.parse::<usize>("color"));
+
+        assert_eq!(
+            Err(ParseError {
+                line: 4,
+                columns: 19..24,
+                expected: "color".to_owned(),
+            }),
+            error
+        );
+    }
+
+    #[test]
+    fn it_fails_at_the_end_of_the_input() {
+        let mut lines = Lines::new("seeds: 79 14\n");
+
+        assert!(lines.expect("seeds").is_ok());
+        assert_eq!(
+            Err(ParseError {
+                line: 2,
+                columns: 0..0,
+                expected: "map".to_owned(),
+            }),
+            lines.expect("map").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn it_derives_readers_from_patterns() {
+        assert_eq!(
+            Ok(Game {
+                id: 12,
+                sets: vec![vec![3, 4], vec![], vec![5]],
+            }),
+            Game::from_line(Cursor::new(1, "Game 12: 3 4;; 5"))
+        );
+        assert_eq!(
+            Err(ParseError {
+                line: 2,
+                columns: 11..12,
+                expected: "integer".to_owned(),
+            }),
+            Game::from_line(Cursor::new(2, "Game 12: 3 x; 5"))
+        );
+    }
+}
@@ -0,0 +1,553 @@
+use std::collections::BTreeMap;
+use std::mem;
+use std::path::{Path, PathBuf};
+
+use crate::examples::Example;
+use crate::html::{self, Token};
+use crate::Part;
+
+/// Returns the path of the saved puzzle page of the `n`th day of `year` within `data_dir`, e.g. `2023/day07.html`.
+pub fn get_page_path(year: u16, n: u8, data_dir: &Path) -> PathBuf {
+    data_dir.join(year.to_string()).join(format!("day{n:02}.html"))
+}
+
+/// Returns whether `page` describes both parts, the second one being revealed once the first is solved.
+pub fn is_complete(page: &str) -> bool {
+    page.contains("id=\"part2\"")
+}
+
+fn is_description(token: &Token) -> bool {
+    token.is_start("article")
+        && token
+            .attr("class")
+            .is_some_and(|class| class.split_whitespace().any(|class| class == "day-desc"))
+}
+
+/// Extracts the `<pre><code>` blocks of the description of each part, in order, the answer expected from the last
+/// block of a part being the last one emphasized as `<code><em>` in its description. The second part expects its
+/// answer from the last block of the first one when it has none.
+pub fn extract_examples(page: &str) -> Vec<Example> {
+    // block texts and last emphasized answer of each part
+    let mut blocks = Vec::<(usize, String)>::default();
+    let mut answers = Vec::<Option<String>>::default();
+    let mut part = None;
+    let (mut pre, mut code, mut em) = (false, false, false);
+    let mut text = String::new();
+    let mut answer = None::<String>;
+
+    for token in html::tokenize(page) {
+        if is_description(&token) {
+            answers.push(None);
+            part = Some(answers.len() - 1);
+            continue;
+        }
+
+        let Some(index) = part else {
+            continue;
+        };
+
+        match token {
+            token if token.is_end("article") => part = None,
+            token if token.is_start("pre") => pre = true,
+            token if token.is_end("pre") => {
+                pre = false;
+                blocks.push((index, mem::take(&mut text)));
+            }
+            Token::Text(s) if pre => text.push_str(&s),
+            Token::Text(s) => {
+                if let Some(answer) = &mut answer {
+                    answer.push_str(&s);
+                }
+            }
+            _ if pre => {}
+            Token::Start { name, .. } => {
+                code |= name == "code";
+                em |= name == "em";
+
+                if code && em && answer.is_none() {
+                    answer = Some(String::new());
+                }
+            }
+            Token::End(name) => {
+                code &= name != "code";
+                em &= name != "em";
+
+                if !(code && em) {
+                    if let Some(answer) = answer.take().filter(|answer| !answer.trim().is_empty()) {
+                        answers[index] = Some(answer.trim().to_owned());
+                    }
+                }
+            }
+        }
+    }
+
+    let mut examples = blocks
+        .iter()
+        .map(|(_, input)| {
+            Example {
+                expected: BTreeMap::default(),
+                input: if input.ends_with('\n') {
+                    input.clone()
+                } else {
+                    format!("{input}\n")
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+    let last_block = |index| blocks.iter().rposition(|(part, _)| *part == index);
+
+    for (index, part) in [Part::One, Part::Two].into_iter().enumerate() {
+        let Some(Some(answer)) = answers.get(index) else {
+            continue;
+        };
+
+        if let Some(block) = last_block(index).or_else(|| last_block(0)) {
+            examples[block].expected.insert(part, answer.clone());
+        }
+    }
+
+    examples
+}
+
+/// Way of rendering a puzzle description.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Style {
+    /// Text styled with ANSI escape codes.
+    Terminal,
+    /// Unstyled text, e.g. for pagers.
+    Plain,
+    Markdown,
+}
+
+const BOLD: &str = "\x1b[1m";
+const CYAN: &str = "\x1b[36m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Block {
+    Heading,
+    Paragraph,
+    /// Item of a list nested at the given depth.
+    Item(usize),
+    Code,
+}
+
+/// Renderer of the content of a page, tag by tag.
+struct Renderer<'a> {
+    style: Style,
+    width: usize,
+    url: &'a str,
+    blocks: Vec<(Block, String)>,
+    /// Text of the block being rendered.
+    text: String,
+    /// Escape codes in effect within the text.
+    codes: Vec<&'static str>,
+    links: Vec<String>,
+    /// Targets of the links being rendered.
+    hrefs: Vec<Option<String>>,
+    lists: usize,
+    pre: bool,
+    code: bool,
+    emphasized_code: bool,
+}
+
+impl Renderer<'_> {
+    fn flush(&mut self, block: Block) {
+        let text = mem::take(&mut self.text);
+
+        if block == Block::Code {
+            self.blocks.push((block, text));
+        } else if !text.trim().is_empty() {
+            self.blocks.push((block, text.trim().to_owned()));
+        }
+    }
+
+    fn push_code(&mut self, code: &'static str) {
+        if self.style == Style::Terminal {
+            self.codes.push(code);
+            self.text.push_str(code);
+        }
+    }
+
+    fn pop_code(&mut self, code: &'static str) {
+        if let Some(index) = self.codes.iter().rposition(|c| *c == code) {
+            self.codes.remove(index);
+            self.text.push_str(RESET);
+            self.text.extend(self.codes.iter().copied());
+        }
+    }
+
+    fn push_text(&mut self, s: &str) {
+        if self.pre {
+            self.text.push_str(s);
+            return;
+        }
+
+        // runs of whitespace are rendered as a single space, as in browsers
+        for (i, word) in s.split(char::is_whitespace).enumerate() {
+            if i > 0 && !self.text.ends_with(' ') {
+                self.text.push(' ');
+            }
+
+            if self.style == Style::Markdown && !self.code {
+                for c in word.chars() {
+                    if "\\`*_[]<>#".contains(c) {
+                        self.text.push('\\');
+                    }
+
+                    self.text.push(c);
+                }
+            } else {
+                self.text.push_str(word);
+            }
+        }
+    }
+
+    fn start(&mut self, token: &Token) {
+        let Token::Start { name, .. } = token else {
+            return;
+        };
+
+        match name.as_str() {
+            "h2" | "p" => self.flush(Block::Paragraph),
+            "ul" | "ol" => {
+                self.flush(self.item());
+                self.lists += 1;
+            }
+            "li" => self.flush(self.item()),
+            "pre" => {
+                self.flush(Block::Paragraph);
+                self.pre = true;
+            }
+            "em" if self.style == Style::Markdown => {
+                // emphasized code is rendered as **`code`**, emphasis being plain text within code
+                if self.code && self.text.ends_with('`') {
+                    self.text.pop();
+                    self.text.push_str("**`");
+                    self.emphasized_code = true;
+                } else if !self.pre && !self.code {
+                    self.text.push_str("**");
+                }
+            }
+            "em" => self.push_code(BOLD),
+            "code" if self.pre => {}
+            "code" => {
+                self.code = true;
+
+                match self.style {
+                    Style::Markdown => self.text.push('`'),
+                    _ => self.push_code(CYAN),
+                }
+            }
+            "a" => {
+                let href = token.attr("href").map(|href| resolve(self.url, href));
+
+                if href.is_some() && self.style == Style::Markdown {
+                    self.text.push('[');
+                }
+
+                self.hrefs.push(href);
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, name: &str) {
+        match name {
+            "h2" => self.flush(Block::Heading),
+            "p" => self.flush(Block::Paragraph),
+            "ul" | "ol" => {
+                self.flush(self.item());
+                self.lists = self.lists.saturating_sub(1);
+            }
+            "li" => self.flush(self.item()),
+            "pre" => {
+                self.flush(Block::Code);
+                self.pre = false;
+            }
+            "em" => {
+                match self.style {
+                    Style::Markdown if !self.pre && !self.code => self.text.push_str("**"),
+                    Style::Markdown => {}
+                    _ => self.pop_code(BOLD),
+                }
+            }
+            "code" if self.pre => {}
+            "code" => {
+                self.code = false;
+
+                match self.style {
+                    Style::Markdown if mem::take(&mut self.emphasized_code) => self.text.push_str("`**"),
+                    Style::Markdown => self.text.push('`'),
+                    _ => self.pop_code(CYAN),
+                }
+            }
+            "a" => {
+                if let Some(Some(href)) = self.hrefs.pop() {
+                    let n = match self.links.iter().position(|link| *link == href) {
+                        Some(index) => index + 1,
+                        None => {
+                            self.links.push(href);
+                            self.links.len()
+                        }
+                    };
+
+                    match self.style {
+                        Style::Markdown => self.text.push_str(&format!("][{n}]")),
+                        _ => self.text.push_str(&format!("[{n}]")),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn item(&self) -> Block {
+        match self.lists {
+            0 => Block::Paragraph,
+            depth => Block::Item(depth),
+        }
+    }
+
+    fn finish(self) -> String {
+        let mut output = String::new();
+        let mut previous = None;
+
+        for (block, text) in &self.blocks {
+            if let Some(previous) = previous {
+                output.push_str(match (previous, block) {
+                    (Block::Item(_), Block::Item(_)) => "\n",
+                    _ => "\n\n",
+                });
+            }
+
+            output.push_str(&self.render(*block, text));
+            previous = Some(*block);
+        }
+
+        if !self.links.is_empty() {
+            output.push('\n');
+
+            for (i, link) in self.links.iter().enumerate() {
+                match self.style {
+                    Style::Markdown => output.push_str(&format!("\n[{}]: {link}", i + 1)),
+                    _ => output.push_str(&format!("\n[{}] {link}", i + 1)),
+                }
+            }
+        }
+
+        output.push('\n');
+        output
+    }
+
+    fn render(&self, block: Block, text: &str) -> String {
+        match (block, self.style) {
+            (Block::Heading, Style::Markdown) => {
+                format!(
+                    "## {}",
+                    text.trim_start_matches(['-', ' ']).trim_end_matches(['-', ' '])
+                )
+            }
+            (Block::Heading, Style::Terminal) => format!("{BOLD}{text}{RESET}"),
+            (Block::Heading, Style::Plain) => text.to_owned(),
+            (Block::Paragraph, Style::Markdown) => text.to_owned(),
+            (Block::Paragraph, _) => wrap(text, self.width, "", ""),
+            (Block::Item(depth), Style::Markdown) => format!("{}- {text}", "  ".repeat(depth - 1)),
+            (Block::Item(depth), _) => {
+                let indent = "  ".repeat(depth - 1);
+
+                wrap(text, self.width, &format!("{indent}- "), &format!("{indent}  "))
+            }
+            (Block::Code, Style::Markdown) => format!("```\n{}\n```", text.trim_end_matches('\n')),
+            (Block::Code, _) => {
+                text.trim_end_matches('\n')
+                    .lines()
+                    .map(|line| format!("    {line}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+        }
+    }
+}
+
+/// Returns the number of columns taken by `s`, escape codes taking none.
+fn width(s: &str) -> usize {
+    let mut width = 0;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|c| *c == 'm');
+        } else {
+            width += 1;
+        }
+    }
+
+    width
+}
+
+/// Wraps the words of `text` at `columns`, the first line starting with `first` and the next ones with `rest`.
+fn wrap(text: &str, columns: usize, first: &str, rest: &str) -> String {
+    let mut lines = Vec::<String>::default();
+    let mut line = first.to_owned();
+    let mut empty = true;
+
+    for word in text.split(' ') {
+        if !empty && width(&line) + 1 + width(word) > columns {
+            lines.push(mem::replace(&mut line, rest.to_owned()));
+            empty = true;
+        }
+
+        if !empty {
+            line.push(' ');
+        }
+
+        line.push_str(word);
+        empty = false;
+    }
+
+    lines.push(line);
+    lines.join("\n")
+}
+
+/// Resolves `href` against the page located at `url`.
+fn resolve(url: &str, href: &str) -> String {
+    let origin = url
+        .find("://")
+        .and_then(|scheme| url[scheme + 3..].find('/').map(|path| &url[..scheme + 3 + path]))
+        .unwrap_or(url);
+
+    if href.contains("://") {
+        href.to_owned()
+    } else if href.starts_with('/') {
+        format!("{origin}{href}")
+    } else if href.starts_with('#') {
+        format!("{url}{href}")
+    } else {
+        match url.rsplit_once('/') {
+            Some((dir, _)) if dir.len() >= origin.len() => format!("{dir}/{href}"),
+            _ => format!("{origin}/{href}"),
+        }
+    }
+}
+
+/// Renders the content of `page` located at `url`, wrapping paragraphs at `width` columns and listing the targets of
+/// the links after the text.
+pub fn render(page: &str, url: &str, style: Style, width: usize) -> String {
+    let tokens = html::tokenize(page);
+    // the puzzle descriptions and answers lie within `<main>`, the rest of the page being navigation
+    let content = match tokens.iter().position(|token| token.is_start("main")) {
+        Some(start) => {
+            let end = tokens
+                .iter()
+                .rposition(|token| token.is_end("main"))
+                .unwrap_or(tokens.len());
+
+            &tokens[start..end.max(start)]
+        }
+        None => &tokens[..],
+    };
+    let mut renderer = Renderer {
+        style,
+        width,
+        url,
+        blocks: Vec::default(),
+        text: String::new(),
+        codes: Vec::default(),
+        links: Vec::default(),
+        hrefs: Vec::default(),
+        lists: 0,
+        pre: false,
+        code: false,
+        emphasized_code: false,
+    };
+    let mut forms = 0;
+
+    for token in content {
+        match token {
+            // the answer forms have nothing to read
+            token if token.is_start("form") => forms += 1,
+            token if token.is_end("form") => forms -= 1,
+            _ if forms > 0 => {}
+            Token::Start { .. } => renderer.start(token),
+            Token::End(name) => renderer.end(name),
+            Token::Text(s) => renderer.push_text(s),
+        }
+    }
+
+    renderer.flush(Block::Paragraph);
+    renderer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1<em>b</em>2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>.</p>
+<p>Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>two1nine
+eightwothree
+</code></pre>
+<p>Adding these together produces <em><code>281</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn it_extracts_examples_and_their_answers() {
+        assert_eq!(
+            vec![
+                Example {
+                    expected: BTreeMap::from([(Part::One, "142".to_owned())]),
+                    input: "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n".to_owned(),
+                },
+                Example {
+                    expected: BTreeMap::from([(Part::Two, "281".to_owned())]),
+                    input: "two1nine\neightwothree\n".to_owned(),
+                },
+            ],
+            extract_examples(PAGE)
+        );
+    }
+
+    #[test]
+    fn it_expects_the_second_answer_from_the_first_example() {
+        let (page, _) = PAGE.split_once("<pre><code>two1nine").unwrap();
+        let page = format!("{page}<p>Now it is <code><em>281</em></code>.</p></article>");
+
+        assert!(!is_complete(
+            PAGE.split_once("<article class=\"day-desc\"><h2 id").unwrap().0
+        ));
+        assert!(is_complete(&page));
+        assert_eq!(
+            vec![Example {
+                expected: BTreeMap::from([(Part::One, "142".to_owned()), (Part::Two, "281".to_owned())]),
+                input: "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n".to_owned(),
+            }],
+            extract_examples(&page)
+        );
+    }
+
+    #[test]
+    fn it_resolves_links_and_wraps_words() {
+        let url = "https://adventofcode.com/2023/day/1";
+
+        assert_eq!("https://adventofcode.com/2023/day/1/input", resolve(url, "1/input"));
+        assert_eq!("https://adventofcode.com/2023/about", resolve(url, "/2023/about"));
+        assert_eq!("https://adventofcode.com/2023/day/1#part2", resolve(url, "#part2"));
+        assert_eq!("https://example.com/", resolve(url, "https://example.com/"));
+        assert_eq!(
+            "- one \x1b[1mtwo\x1b[0m\n  three",
+            wrap("one \x1b[1mtwo\x1b[0m three", 9, "- ", "  ")
+        );
+    }
+}
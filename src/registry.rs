@@ -0,0 +1,92 @@
+use std::any::{self, Any};
+
+use itertools::Itertools;
+
+use crate::answer::Answer;
+use crate::parser::ParseError;
+use crate::{Day, Part};
+
+/// Puzzle input parsed by the registered parser of any day.
+pub struct Model(Box<dyn Any>);
+
+impl Model {
+    pub fn new<T: Any>(model: T) -> Self {
+        Self(Box::new(model))
+    }
+}
+
+/// Input parser of a day, registered with `#[aoc(year = Y, day = N)]`.
+#[derive(Debug)]
+pub struct Parser {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub parse: fn(&str) -> Result<Model, ParseError>,
+}
+
+/// Solver of a part of a day, registered with `#[aoc(year = Y, day = N, part = P)]`.
+#[derive(Debug)]
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub name: &'static str,
+    /// Label telling an alternative solver apart from the main one.
+    pub variant: Option<&'static str>,
+    pub solve: fn(&Model) -> Answer,
+}
+
+inventory::collect!(Parser);
+inventory::collect!(Solution);
+
+pub fn parsers() -> impl Iterator<Item = &'static Parser> {
+    inventory::iter::<Parser>
+        .into_iter()
+        .sorted_by_key(|parser| (parser.year, parser.day))
+}
+
+pub fn solutions() -> impl Iterator<Item = &'static Solution> {
+    inventory::iter::<Solution>
+        .into_iter()
+        .sorted_by_key(|solution| (solution.year, solution.day, solution.part, solution.variant))
+}
+
+/// Returns the main solver of `part` of `day`, if registered.
+pub fn solution(day: Day, part: Part) -> Option<&'static Solution> {
+    solutions().find(|solution| {
+        solution.year == day.year()
+            && solution.day == day.number()
+            && solution.part == part
+            && solution.variant.is_none()
+    })
+}
+
+/// Borrows the argument of a solver from a model, panicking when the model was parsed by another day.
+pub trait FromModel<'a> {
+    fn from_model(model: &'a Model) -> Self;
+}
+
+impl<'a, T: Any> FromModel<'a> for &'a T {
+    fn from_model(model: &'a Model) -> Self {
+        downcast(model)
+    }
+}
+
+impl<'a, T: Any> FromModel<'a> for &'a [T] {
+    fn from_model(model: &'a Model) -> Self {
+        downcast::<Vec<T>>(model)
+    }
+}
+
+impl<'a> FromModel<'a> for &'a str {
+    fn from_model(model: &'a Model) -> Self {
+        downcast::<String>(model)
+    }
+}
+
+fn downcast<T: Any>(model: &Model) -> &T {
+    model
+        .0
+        .downcast_ref()
+        .unwrap_or_else(|| panic!("model is not a `{}`", any::type_name::<T>()))
+}
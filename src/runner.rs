@@ -0,0 +1,81 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::timing::{self, Phase, Timings};
+use crate::{input, Answer, Day, ParseError, Part};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("cannot read input '{}'", .path.display())]
+    Input {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("cannot parse input '{}'", .path.display())]
+    Parse {
+        path: PathBuf,
+        input: String,
+        #[source]
+        source: ParseError,
+    },
+}
+
+/// Outcome of a phase of the resolution of a day.
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub day: Day,
+    pub phase: Phase,
+    pub answer: Option<Answer>,
+    pub timings: Timings,
+}
+
+/// Loads the input at `path`, parses it and solves `parts` of `day` `repeat` times, timing each phase.
+pub fn solve_day(day: Day, path: &Path, parts: &[Part], repeat: u32) -> Result<Vec<Record>, Error> {
+    let mut records = [Phase::Load, Phase::Parse]
+        .into_iter()
+        .chain(parts.iter().copied().map(Phase::Solve))
+        .map(|phase| {
+            Record {
+                day,
+                phase,
+                answer: None,
+                timings: Timings::default(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for _ in 0..repeat {
+        let (input, elapsed) = timing::measure(|| input::read_input(path));
+        let input = input.map_err(|source| {
+            Error::Input {
+                path: path.to_owned(),
+                source,
+            }
+        })?;
+
+        records[0].timings.push(elapsed);
+
+        let (model, elapsed) = timing::measure(|| crate::parse(day, &input));
+        let model = model.map_err(|source| {
+            Error::Parse {
+                path: path.to_owned(),
+                input: input.clone(),
+                source,
+            }
+        })?;
+
+        records[1].timings.push(elapsed);
+
+        for (record, part) in records[2..].iter_mut().zip(parts) {
+            let (answer, elapsed) = timing::measure(|| crate::solve(day, *part, &model));
+
+            record.answer = Some(answer);
+            record.timings.push(elapsed);
+        }
+    }
+
+    Ok(records)
+}
@@ -0,0 +1,276 @@
+use std::str::FromStr;
+
+use itertools::Itertools;
+use thiserror::Error;
+
+use crate::Day;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DaySelectionError {
+    #[error("invalid day '{0}', expected e.g. `7`, `07`, `day7`, `day07`, `2023/7` or `latest`")]
+    InvalidDay(String),
+    #[error("range from {0} to {1} spans several years")]
+    InvalidRange(u16, u16),
+    #[error("range `{0}` selects no day")]
+    EmptyRange(String),
+    #[error("day {1} of {0} is not implemented, implemented days are {}", implemented_days(*.0))]
+    UnknownDay(u16, u8),
+    #[error("no day of {0} is implemented, implemented years are {}", Day::years().join(", "))]
+    UnknownYear(u16),
+}
+
+fn implemented_days(year: u16) -> String {
+    Day::iter().filter(|day| day.year() == year).map(Day::number).join(", ")
+}
+
+/// Returns the `n`th day of `year`, if implemented.
+fn find(year: u16, n: u8) -> Result<Day, DaySelectionError> {
+    Day::from_number(year, n).ok_or_else(|| {
+        if Day::years().contains(&year) {
+            DaySelectionError::UnknownDay(year, n)
+        } else {
+            DaySelectionError::UnknownYear(year)
+        }
+    })
+}
+
+/// Day given on the command line, either `7`, `07`, `day7`, `day07` or `latest` for the last implemented one,
+/// optionally prefixed by its year as in `2023/7`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DayArg {
+    year: Option<u16>,
+    /// Day of the month, `None` standing for the latest one.
+    n: Option<u8>,
+}
+
+impl DayArg {
+    /// Returns the year and the day of the month, `year` standing for an unspecified one, whether the day is
+    /// implemented or not.
+    pub fn number(self, year: u16) -> Result<(u16, u8), DaySelectionError> {
+        let year = self.year.unwrap_or(year);
+
+        match self.n {
+            Some(n) => Ok((year, n)),
+            None => {
+                Day::iter()
+                    .filter(|day| day.year() == year)
+                    .last()
+                    .map(|day| (year, day.number()))
+                    .ok_or(DaySelectionError::UnknownYear(year))
+            }
+        }
+    }
+
+    /// Returns the implemented day, `year` standing for an unspecified one.
+    pub fn resolve(self, year: u16) -> Result<Day, DaySelectionError> {
+        let (year, n) = self.number(year)?;
+
+        find(year, n)
+    }
+}
+
+impl FromStr for DayArg {
+    type Err = DaySelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DaySelectionError::InvalidDay(s.to_owned());
+        let (year, day) = match s.split_once('/') {
+            Some((year, day)) => (Some(year.parse::<u16>().map_err(|_| invalid())?), day),
+            None => (None, s),
+        };
+
+        if day == "latest" {
+            return Ok(Self { year, n: None });
+        }
+
+        let n = day
+            .strip_prefix("day")
+            .unwrap_or(day)
+            .parse::<u8>()
+            .ok()
+            .filter(|n| (1..=25).contains(n))
+            .ok_or_else(invalid)?;
+
+        Ok(Self { year, n: Some(n) })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    All,
+    Day(DayArg),
+    Range {
+        start: DayArg,
+        end: DayArg,
+        inclusive: bool,
+    },
+}
+
+impl Item {
+    fn resolve(&self, year: u16) -> Result<Vec<Day>, DaySelectionError> {
+        match self {
+            Self::All => {
+                let days = Day::iter().filter(|day| day.year() == year).collect::<Vec<_>>();
+
+                if days.is_empty() {
+                    return Err(DaySelectionError::UnknownYear(year));
+                }
+
+                Ok(days)
+            }
+            Self::Day(day) => Ok(vec![day.resolve(year)?]),
+            Self::Range { start, end, inclusive } => {
+                let (start_year, start) = start.number(year)?;
+                let (end_year, end) = end.number(year)?;
+
+                if start_year != end_year {
+                    return Err(DaySelectionError::InvalidRange(start_year, end_year));
+                }
+
+                if start > end || (start == end && !inclusive) {
+                    let operator = if *inclusive { "..=" } else { ".." };
+
+                    return Err(DaySelectionError::EmptyRange(format!("{start}{operator}{end}")));
+                }
+
+                let end = if *inclusive { end } else { end - 1 };
+
+                (start..=end).map(|n| find(start_year, n)).collect()
+            }
+        }
+    }
+}
+
+impl FromStr for Item {
+    type Err = DaySelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+
+        match s.split_once("..") {
+            Some((start, end)) => {
+                let (end, inclusive) = match end.strip_prefix('=') {
+                    Some(end) => (end, true),
+                    None => (end, false),
+                };
+
+                Ok(Self::Range {
+                    start: start.parse()?,
+                    end: end.parse()?,
+                    inclusive,
+                })
+            }
+            None => Ok(Self::Day(s.parse()?)),
+        }
+    }
+}
+
+/// Days selected on the command line, either `all`, a day (`7`, `day07`, `2023/7`, `latest`), a range of days (`3..7`,
+/// `3..=latest`) or a comma-separated list of them, days without a year belonging to the one in effect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(Vec<Item>);
+
+impl DaySelection {
+    /// Returns the selected days in order, all of them having to be implemented, `year` standing for an unspecified
+    /// one.
+    pub fn resolve(&self, year: u16) -> Result<Vec<Day>, DaySelectionError> {
+        let days = self
+            .0
+            .iter()
+            .map(|item| item.resolve(year))
+            .flatten_ok()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(days.into_iter().sorted().dedup().collect())
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = DaySelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|s| s.trim().parse())
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_numbers(s: &str) -> Result<Vec<u8>, DaySelectionError> {
+        s.parse::<DaySelection>()?
+            .resolve(2023)
+            .map(|days| days.into_iter().map(Day::number).collect())
+    }
+
+    fn latest() -> Day {
+        Day::iter().filter(|day| day.year() == 2023).last().unwrap()
+    }
+
+    fn resolve(s: &str) -> Result<Day, DaySelectionError> {
+        s.parse::<DayArg>()?.resolve(2023)
+    }
+
+    #[test]
+    fn it_parses_days_in_every_spelling() {
+        for s in ["7", "07", "day7", "day07", "2023/7", "2023/day07"] {
+            assert_eq!(Ok(Day::from_number(2023, 7).unwrap()), resolve(s));
+        }
+
+        assert_eq!(Ok(latest()), resolve("latest"));
+        assert_eq!(Ok(latest()), resolve("2023/latest"));
+        assert_eq!(Ok((2022, 7)), "7".parse::<DayArg>().and_then(|day| day.number(2022)));
+    }
+
+    #[test]
+    fn it_parses_lists_and_ranges() {
+        assert_eq!(
+            Ok(Day::iter()
+                .filter(|day| day.year() == 2023)
+                .map(Day::number)
+                .collect::<Vec<_>>()),
+            parse_numbers("all")
+        );
+        assert_eq!(Ok(vec![1, 3, 5]), parse_numbers("5,1,3"));
+        assert_eq!(Ok(vec![2, 3, 4]), parse_numbers("2..=4"));
+        assert_eq!(Ok(vec![1, 2, 3]), parse_numbers("day01..day04,2"));
+        assert_eq!(Ok((5..=latest().number()).collect()), parse_numbers("2023/5..=latest"));
+    }
+
+    #[test]
+    fn it_rejects_unknown_days() {
+        assert_eq!(Err(DaySelectionError::UnknownDay(2023, 24)), parse_numbers("24"));
+        assert_eq!(Err(DaySelectionError::InvalidDay("26".to_owned())), parse_numbers("26"));
+        assert_eq!(
+            Err(DaySelectionError::InvalidDay("x".to_owned())),
+            parse_numbers("1..=x")
+        );
+        assert_eq!(Err(DaySelectionError::UnknownYear(2014)), parse_numbers("2014/1"));
+        assert_eq!(
+            Err(DaySelectionError::InvalidRange(2022, 2023)),
+            parse_numbers("2022/1..=2023/2")
+        );
+    }
+
+    #[test]
+    fn it_rejects_empty_ranges() {
+        assert_eq!(
+            Err(DaySelectionError::EmptyRange("7..3".to_owned())),
+            parse_numbers("7..3")
+        );
+        assert_eq!(
+            Err(DaySelectionError::EmptyRange("1..1".to_owned())),
+            parse_numbers("1..1")
+        );
+        assert_eq!(
+            Err(DaySelectionError::EmptyRange("4..=2".to_owned())),
+            parse_numbers("day04..=2")
+        );
+        assert_eq!(Ok(vec![1]), parse_numbers("1..=1"));
+    }
+}
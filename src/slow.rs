@@ -0,0 +1,5 @@
+/// Days taking minutes to solve, along with the reason why, only solved by the tests, benchmarks and baselines when
+/// explicitly asked for.
+///
+/// Shared with the build script, which ignores the answer tests of these days.
+pub const SLOW_DAYS: [(u16, u8, &str); 1] = [(2023, 5, "part 2 is brute-forced")];
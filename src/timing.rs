@@ -0,0 +1,85 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::Part;
+
+/// Step of the resolution of a day.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Load,
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Load => "load".fmt(f),
+            Self::Parse => "parse".fmt(f),
+            Self::Solve(part) => format!("part{part}").fmt(f),
+        }
+    }
+}
+
+/// Wall-clock times measured over the repeated runs of a phase.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timings(Vec<Duration>);
+
+impl Timings {
+    pub fn push(&mut self, elapsed: Duration) {
+        self.0.push(elapsed);
+    }
+
+    pub fn min(&self) -> Duration {
+        self.0.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.0.iter().max().copied().unwrap_or_default()
+    }
+
+    /// Returns the median time, averaging the two middle ones for an even number of runs.
+    pub fn median(&self) -> Duration {
+        let mut timings = self.0.clone();
+
+        timings.sort();
+
+        match timings.len() {
+            0 => Duration::ZERO,
+            n if n % 2 == 0 => (timings[n / 2 - 1] + timings[n / 2]) / 2,
+            n => timings[n / 2],
+        }
+    }
+}
+
+/// Calls `f`, returning its result along with the wall-clock time it took.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let now = Instant::now();
+    let result = f();
+
+    (result, now.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings(millis: &[u64]) -> Timings {
+        Timings(millis.iter().copied().map(Duration::from_millis).collect())
+    }
+
+    #[test]
+    fn it_computes_statistics() {
+        let timings = timings(&[7, 1, 3]);
+
+        assert_eq!(Duration::from_millis(1), timings.min());
+        assert_eq!(Duration::from_millis(3), timings.median());
+        assert_eq!(Duration::from_millis(7), timings.max());
+    }
+
+    #[test]
+    fn it_averages_the_median_of_an_even_number_of_runs() {
+        assert_eq!(Duration::from_millis(4), timings(&[7, 1, 3, 5]).median());
+        assert_eq!(Duration::ZERO, timings(&[]).median());
+    }
+}
@@ -0,0 +1,243 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{fs, io, thread};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{input, puzzle, Answer, Part};
+
+/// Website serving the puzzles.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool and its author to the website, as its maintainers ask automated requests to.
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_AUTHORS"),
+    ")"
+);
+
+/// Minimum time between two requests of a same [`Client`] to the website.
+///
+/// Requests are only spaced out within a single invocation, consecutive `aoc fetch` or `aoc submit` processes not
+/// waiting for each other.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("the session token is invalid or has expired")]
+    Session,
+    #[error("day {1} of {0} is not unlocked yet")]
+    Locked(u16, u8),
+    #[error("unexpected response status {0}")]
+    Status(u16),
+    #[error("unexpected response '{0}'")]
+    Response(String),
+    #[error(transparent)]
+    Transport(Box<ureq::Transport>),
+    #[error("cannot read the response")]
+    Read(#[source] io::Error),
+    #[error("cannot write '{}'", .path.display())]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// Outcome of the fetching of a puzzle input or page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The file was already in the data directory.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Judgement of a submitted answer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, without telling more.
+    Wrong,
+    /// The answer was not judged, another one having been submitted too recently.
+    Wait(Duration),
+    /// The answer was not judged, the part being either already solved or locked until the first one is.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Reads the verdict from the page answering a submission.
+    pub fn from_page(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Some(Self::TooHigh)
+            } else if page.contains("your answer is too low") {
+                Some(Self::TooLow)
+            } else {
+                Some(Self::Wrong)
+            }
+        } else if page.contains("You gave an answer too recently") {
+            let wait = page.split_once("You have ")?.1.split_once(" left to wait")?.0;
+
+            parse_wait(wait).map(Self::Wait)
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Self::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses a time to wait like `1m 5s`.
+fn parse_wait(s: &str) -> Option<Duration> {
+    s.split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value = value.parse::<u64>().ok()?;
+
+            match unit {
+                "s" => Some(value),
+                "m" => Some(value * 60),
+                "h" => Some(value * 3600),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Client of the website acting on behalf of the user owning a session token, spacing its requests out.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, interval: Duration) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+            interval,
+            last_request: None,
+        }
+    }
+
+    /// Downloads the input of the `n`th day of `year` into `data_dir`, unless it is already there.
+    pub fn fetch_input(&mut self, year: u16, n: u8, data_dir: &Path) -> Result<Fetched, Error> {
+        let path = input::get_input_path_by_number(year, n, data_dir);
+
+        // scaffolded days come with an empty input
+        if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let request = self.request("GET", &format!("/{year}/day/{n}/input"));
+        let input = read_response(year, n, request.call())?;
+
+        write(&path, &input)?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Downloads the puzzle page of the `n`th day of `year` into `data_dir`, unless it is already there with both
+    /// parts, the second one being revealed once the first is solved.
+    pub fn fetch_puzzle(&mut self, year: u16, n: u8, data_dir: &Path) -> Result<Fetched, Error> {
+        let path = puzzle::get_page_path(year, n, data_dir);
+
+        if fs::read_to_string(&path).is_ok_and(|page| puzzle::is_complete(&page)) {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let request = self.request("GET", &format!("/{year}/day/{n}"));
+        let page = read_response(year, n, request.call())?;
+
+        write(&path, &page)?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Submits `answer` to `part` of the `n`th day of `year`.
+    pub fn submit_answer(&mut self, year: u16, n: u8, part: Part, answer: &Answer) -> Result<Verdict, Error> {
+        let request = self.request("POST", &format!("/{year}/day/{n}/answer"));
+        let page = read_response(
+            year,
+            n,
+            request.send_form(&[("level", &part.to_string()), ("answer", &answer.to_string())]),
+        )?;
+
+        Verdict::from_page(&page).ok_or(Error::Response(page))
+    }
+
+    fn request(&mut self, method: &str, path: &str) -> ureq::Request {
+        if let Some(elapsed) = self.last_request.map(|last_request| last_request.elapsed()) {
+            thread::sleep(self.interval.saturating_sub(elapsed));
+        }
+
+        self.last_request = Some(Instant::now());
+
+        self.agent
+            .request(method, &format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, contents))
+        .map_err(|source| {
+            Error::Write {
+                path: path.to_owned(),
+                source,
+            }
+        })
+}
+
+fn read_response(year: u16, n: u8, response: Result<ureq::Response, ureq::Error>) -> Result<String, Error> {
+    match response {
+        Ok(response) => response.into_string().map_err(Error::Read),
+        // the website answers 400 to unknown sessions and 404 to puzzles not yet unlocked
+        Err(ureq::Error::Status(400 | 401 | 403, _)) => Err(Error::Session),
+        Err(ureq::Error::Status(404, _)) => Err(Error::Locked(year, n)),
+        Err(ureq::Error::Status(status, _)) => Err(Error::Status(status)),
+        Err(ureq::Error::Transport(transport)) => Err(Error::Transport(Box::new(transport))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reads_verdicts() {
+        assert_eq!(
+            Some(Verdict::Correct),
+            Verdict::from_page("<article><p>That's the right answer!  You are one gold star closer.</p></article>")
+        );
+        assert_eq!(
+            Some(Verdict::TooHigh),
+            Verdict::from_page("That's not the right answer; your answer is too high.  If you're stuck...")
+        );
+        assert_eq!(
+            Some(Verdict::TooLow),
+            Verdict::from_page("That's not the right answer; your answer is too low.")
+        );
+        assert_eq!(
+            Some(Verdict::Wait(Duration::from_secs(65))),
+            Verdict::from_page("You gave an answer too recently. You have 1m 5s left to wait.")
+        );
+        assert_eq!(None, Verdict::from_page("<html></html>"));
+    }
+}
@@ -0,0 +1 @@
+mod y2023;
@@ -0,0 +1,89 @@
+use aoc_macros::aoc;
+
+use crate::parser::{Lines, ParseError};
+
+const RADIX: u32 = 10;
+const NUMBERS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// Returns the digits of `s`, spelled out or not.
+fn spelled_digits(s: &str) -> impl DoubleEndedIterator<Item = u32> + '_ {
+    s.char_indices().filter_map(|(i, c)| {
+        c.to_digit(RADIX).or_else(|| {
+            NUMBERS
+                .iter()
+                .enumerate()
+                .find_map(|(j, number)| s[i..].starts_with(number).then_some((j + 1) as u32))
+        })
+    })
+}
+
+#[aoc(year = 2023, day = 1)]
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    Lines::new(input)
+        .map(|cursor| {
+            let line = cursor.rest();
+
+            if spelled_digits(line).next().is_none() {
+                return Err(cursor.error_at(line, "digit"));
+            }
+
+            Ok(line.to_owned())
+        })
+        .collect()
+}
+
+#[aoc(year = 2023, day = 1, part = 1)]
+fn get_solution_part1(lines: &[String]) -> usize {
+    lines
+        .iter()
+        // lines only spelling their digits out, e.g. in the example of part 2, carry no calibration value
+        .filter_map(|s| {
+            let mut digits = s.chars().filter_map(|c| c.to_digit(RADIX));
+
+            let first = digits.next()?;
+            let last = digits.next_back().unwrap_or(first);
+
+            Some((first * RADIX + last) as usize)
+        })
+        .sum::<usize>()
+}
+
+#[aoc(year = 2023, day = 1, part = 2)]
+fn get_solution_part2(lines: &[String]) -> usize {
+    lines
+        .iter()
+        .map(|s| {
+            let mut digits = spelled_digits(s);
+
+            let first = digits.next().expect("parsed lines have a digit");
+            let last = digits.next_back().unwrap_or(first);
+
+            (first * RADIX + last) as usize
+        })
+        .sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_lines_without_digit() {
+        assert_eq!(
+            Err(ParseError {
+                line: 2,
+                columns: 0..3,
+                expected: "digit".to_owned(),
+            }),
+            parse_input("1abc2\nabc\n")
+        );
+    }
+
+    #[test]
+    fn it_skips_spelled_out_lines_in_part1() {
+        let lines = parse_input("eightwothree\na1b2c3").unwrap();
+
+        assert_eq!(13, get_solution_part1(&lines));
+        assert_eq!(83 + 13, get_solution_part2(&lines));
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_macros::{aoc, FromInput};
+use strum::EnumString;
+
+use crate::parser::{FromInput, Lines, ParseError};
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, EnumString, FromInput)]
+#[strum(serialize_all = "lowercase")]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[derive(FromInput)]
+#[input("{count} {color}")]
+struct Cube {
+    count: usize,
+    color: Color,
+}
+
+#[derive(FromInput)]
+#[input("{cubes:,}")]
+struct Set {
+    cubes: Vec<Cube>,
+}
+
+impl Set {
+    fn get(&self, color: Color) -> usize {
+        self.cubes
+            .iter()
+            .filter(|cube| cube.color == color)
+            .map(|cube| cube.count)
+            .sum()
+    }
+}
+
+#[derive(FromInput)]
+#[input("Game {id}: {sets:;}")]
+struct Record {
+    id: usize,
+    sets: Vec<Set>,
+}
+
+#[aoc(year = 2023, day = 2)]
+fn parse_input(input: &str) -> Result<Vec<Record>, ParseError> {
+    Lines::new(input).map(Record::from_line).collect()
+}
+
+#[aoc(year = 2023, day = 2, part = 1)]
+fn get_solution_part1(records: &[Record]) -> usize {
+    records
+        .iter()
+        .filter_map(|Record { id, sets }| {
+            sets.iter()
+                .all(|set| set.get(Color::Red) <= 12 && set.get(Color::Green) <= 13 && set.get(Color::Blue) <= 14)
+                .then_some(*id)
+        })
+        .sum::<usize>()
+}
+
+#[aoc(year = 2023, day = 2, part = 2)]
+fn get_solution_part2(records: &[Record]) -> usize {
+    records
+        .iter()
+        .map(|Record { sets, .. }| {
+            let (red, green, blue) = sets.iter().fold((0, 0, 0), |acc, set| {
+                (
+                    set.get(Color::Red).max(acc.0),
+                    set.get(Color::Green).max(acc.1),
+                    set.get(Color::Blue).max(acc.2),
+                )
+            });
+
+            red * green * blue
+        })
+        .sum::<usize>()
+}
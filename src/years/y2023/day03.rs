@@ -1,3 +1,7 @@
+use aoc_macros::aoc;
+
+use crate::parser::ParseError;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 struct Coordinate {
     x: i32,
@@ -24,11 +28,8 @@ struct Schematic {
     symbols: Vec<Coordinate>,
 }
 
-fn get_input() -> &'static str {
-    include_str!("../../data/day03.txt")
-}
-
-fn parse_input(input: &str) -> Schematic {
+#[aoc(year = 2023, day = 3)]
+fn parse_input(input: &str) -> Result<Schematic, ParseError> {
     let raw_repr = input
         .lines()
         .map(|s| {
@@ -92,11 +93,11 @@ fn parse_input(input: &str) -> Schematic {
         })
         .collect();
 
-    Schematic {
+    Ok(Schematic {
         raw_repr,
         numbers,
         symbols,
-    }
+    })
 }
 
 fn get_adjacent_coordinates(Coordinate { x, y }: Coordinate) -> impl Iterator<Item = Coordinate> {
@@ -114,7 +115,8 @@ fn get_adjacent_coordinates(Coordinate { x, y }: Coordinate) -> impl Iterator<It
     .filter(|coordinate| coordinate.y >= 0 && coordinate.x >= 0)
 }
 
-fn get_solution_part1(schematic: Schematic) -> usize {
+#[aoc(year = 2023, day = 3, part = 1)]
+fn get_solution_part1(schematic: &Schematic) -> usize {
     schematic
         .numbers
         .iter()
@@ -143,7 +145,8 @@ fn get_solution_part1(schematic: Schematic) -> usize {
         .sum::<usize>()
 }
 
-fn get_solution_part2(schematic: Schematic) -> usize {
+#[aoc(year = 2023, day = 3, part = 2)]
+fn get_solution_part2(schematic: &Schematic) -> usize {
     schematic
         .symbols
         .iter()
@@ -182,52 +185,3 @@ fn get_solution_part2(schematic: Schematic) -> usize {
         })
         .sum::<usize>()
 }
-
-pub fn solve_part1() {
-    let input = get_input();
-    let schematic = parse_input(input);
-    let solution = get_solution_part1(schematic);
-
-    println!("{solution}");
-}
-
-pub fn solve_part2() {
-    let input = get_input();
-    let schematic = parse_input(input);
-    let solution = get_solution_part2(schematic);
-
-    println!("{solution}");
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "\
-467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..";
-
-    #[test]
-    fn it_solves_example_part1() {
-        let schematic = parse_input(INPUT);
-        let solution = get_solution_part1(schematic);
-
-        assert_eq!(4361, solution);
-    }
-
-    #[test]
-    fn it_solves_example_part2() {
-        let schematic = parse_input(INPUT);
-        let solution = get_solution_part2(schematic);
-
-        assert_eq!(467835, solution);
-    }
-}
@@ -0,0 +1,59 @@
+use aoc_macros::{aoc, FromInput};
+
+use crate::parser::{FromInput, Lines, ParseError};
+
+#[derive(FromInput)]
+#[input("Card {_}: {winning_numbers} | {scratched_numbers}")]
+struct Card {
+    winning_numbers: Vec<usize>,
+    scratched_numbers: Vec<usize>,
+}
+
+impl Card {
+    fn score(&self) -> usize {
+        self.scratched_numbers
+            .iter()
+            .filter(|number| self.winning_numbers.contains(number))
+            .fold(0, |acc, _| match acc {
+                0 => 1,
+                _ => acc * 2,
+            })
+    }
+
+    fn matching_numbers(&self) -> usize {
+        self.scratched_numbers
+            .iter()
+            .filter(|number| self.winning_numbers.contains(number))
+            .count()
+    }
+}
+
+#[aoc(year = 2023, day = 4)]
+fn parse_input(input: &str) -> Result<Vec<Card>, ParseError> {
+    Lines::new(input).map(Card::from_line).collect()
+}
+
+#[aoc(year = 2023, day = 4, part = 1)]
+fn get_solution_part1(cards: &[Card]) -> usize {
+    cards.iter().map(Card::score).sum()
+}
+
+#[aoc(year = 2023, day = 4, part = 2)]
+fn get_solution_part2(cards: &[Card]) -> usize {
+    let mut scratchcards = vec![1; cards.len()];
+
+    for (i, card) in cards.iter().enumerate() {
+        match card.matching_numbers() {
+            0 => continue,
+            n => {
+                let copied_scratchcard = scratchcards[i];
+
+                for scratchcard in scratchcards[i + 1..=i + n].iter_mut() {
+                    *scratchcard += copied_scratchcard;
+                }
+            }
+        }
+    }
+
+    scratchcards.iter().sum()
+}
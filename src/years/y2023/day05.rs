@@ -0,0 +1,158 @@
+use std::ops::Range;
+
+use aoc_macros::aoc;
+
+use crate::parser::{Cursor, Lines, ParseError};
+
+#[derive(Debug)]
+struct Seeds(Vec<usize>);
+
+impl Seeds {
+    fn parse(mut cursor: Cursor) -> Result<Self, ParseError> {
+        cursor.tag("seeds:")?;
+
+        let mut seeds = Vec::default();
+
+        // part 2 reads the seeds as pairs of range start and length
+        while !cursor.is_empty() {
+            seeds.push(cursor.integer()?);
+            seeds.push(cursor.integer().map_err(|_| cursor.error("seed range length"))?);
+        }
+
+        if seeds.is_empty() {
+            return Err(cursor.error("seed"));
+        }
+
+        Ok(Self(seeds))
+    }
+}
+
+#[derive(Debug)]
+struct Map(Vec<MapEntry>);
+
+impl Map {
+    fn parse(mut header: Cursor, lines: &mut Lines) -> Result<Self, ParseError> {
+        header.until("map:")?.word()?;
+        header.end()?;
+
+        let entries = lines
+            .take_while(|cursor| !cursor.is_empty())
+            .map(MapEntry::parse)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Map(entries))
+    }
+
+    fn transform(&self, source: usize) -> usize {
+        self.0
+            .iter()
+            .flat_map(|entry| entry.transform(source))
+            .next()
+            .unwrap_or(source)
+    }
+}
+
+#[derive(Debug)]
+struct MapEntry {
+    destination: Range<usize>,
+    source: Range<usize>,
+}
+
+impl MapEntry {
+    fn parse(mut cursor: Cursor) -> Result<Self, ParseError> {
+        let destination = cursor.integer::<usize>()?;
+        let source = cursor.integer::<usize>()?;
+        let length = cursor.integer::<usize>()?;
+
+        cursor.end()?;
+
+        Ok(MapEntry {
+            destination: destination..(destination + length),
+            source: source..(source + length),
+        })
+    }
+
+    #[allow(clippy::unnecessary_lazy_evaluations)]
+    fn transform(&self, source: usize) -> Option<usize> {
+        self.source
+            .contains(&source)
+            // must be kept to avoid subtrat with overflow due to eager initialization with `then_some`
+            .then(|| self.destination.start + (source - self.source.start))
+    }
+}
+
+#[derive(Debug)]
+struct Almanax {
+    seeds: Seeds,
+    maps: Vec<Map>,
+}
+
+#[aoc(year = 2023, day = 5)]
+fn parse_input(input: &str) -> Result<Almanax, ParseError> {
+    let mut lines = Lines::new(input);
+
+    let seeds = Seeds::parse(lines.expect("seeds")?)?;
+
+    lines.expect("empty line")?.end()?;
+
+    let mut maps = Vec::default();
+
+    while let Some(header) = lines.next() {
+        maps.push(Map::parse(header, &mut lines)?);
+    }
+
+    Ok(Almanax { seeds, maps })
+}
+
+#[aoc(year = 2023, day = 5, part = 1)]
+fn get_solution_part1(almanax: &Almanax) -> usize {
+    almanax
+        .seeds
+        .0
+        .iter()
+        .map(|seed| almanax.maps.iter().fold(*seed, |source, map| map.transform(source)))
+        .min()
+        .unwrap()
+}
+
+#[aoc(year = 2023, day = 5, part = 2)]
+fn get_solution_part2(almanax: &Almanax) -> usize {
+    almanax
+        .seeds
+        .0
+        .chunks(2)
+        // definitely not the quickest solution but who cares ?
+        .flat_map(|v| v[0]..(v[0] + v[1]))
+        .map(|seed| almanax.maps.iter().fold(seed, |source, map| map.transform(source)))
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_odd_seed_count() {
+        assert_eq!(
+            Err(ParseError {
+                line: 1,
+                columns: 12..12,
+                expected: "seed range length".to_owned(),
+            }),
+            parse_input("seeds: 1 2 3\n").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn it_rejects_empty_seed_list() {
+        assert_eq!(
+            Err(ParseError {
+                line: 1,
+                columns: 6..6,
+                expected: "seed".to_owned(),
+            }),
+            parse_input("seeds:\n\n").map(|_| ())
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use aoc_macros::{aoc, FromInput};
+
+use crate::parser::{FromInput, Lines, ParseError};
+
+struct Record {
+    time: usize,
+    distance: usize,
+}
+
+impl Record {
+    fn compute_winning_starting_times(&self) -> usize {
+        (0..self.time)
+            .filter(|holding_time| {
+                let starting_speed = holding_time;
+                let remaining_time = self.time - holding_time;
+                let reached_distance = remaining_time * starting_speed;
+
+                reached_distance > self.distance
+            })
+            .count()
+    }
+}
+
+#[derive(FromInput)]
+#[input("Time: {0}")]
+struct Times(Vec<usize>);
+
+#[aoc(year = 2023, day = 6)]
+fn parse_input(input: &str) -> Result<Vec<Record>, ParseError> {
+    let mut lines = Lines::new(input);
+
+    let mut cursor = lines.expect("times")?;
+    let Times(times) = Times::from_line(cursor.take())?;
+
+    if times.is_empty() {
+        return Err(cursor.error("time"));
+    }
+
+    let mut cursor = lines.expect("distances")?;
+
+    cursor.tag("Distance:")?;
+
+    // as many distances as times are expected, the first missing or extra one being reported
+    let distances = times
+        .iter()
+        .map(|_| {
+            if cursor.is_empty() {
+                Err(cursor.error("distance"))
+            } else {
+                cursor.integer()
+            }
+        })
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    if !cursor.is_empty() {
+        return Err(cursor.error("end of line after one distance per time"));
+    }
+
+    let records = times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Record { time, distance })
+        .collect();
+
+    Ok(records)
+}
+
+#[aoc(year = 2023, day = 6, part = 1)]
+fn get_solution_part1(records: &[Record]) -> usize {
+    records.iter().map(Record::compute_winning_starting_times).product()
+}
+
+#[aoc(year = 2023, day = 6, part = 2)]
+fn get_solution_part2(records: &[Record]) -> usize {
+    // numbers are not separated by spaces but kerned together
+    let kern = |f: fn(&Record) -> usize| {
+        records
+            .iter()
+            .map(|record| f(record).to_string())
+            .collect::<String>()
+            .parse()
+            .unwrap()
+    };
+
+    let record = Record {
+        time: kern(|record| record.time),
+        distance: kern(|record| record.distance),
+    };
+
+    record.compute_winning_starting_times()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_points_at_the_first_missing_or_extra_distance() {
+        assert_eq!(
+            Err(ParseError {
+                line: 2,
+                columns: 14..14,
+                expected: "distance".to_owned(),
+            }),
+            parse_input("Time: 7 15 30\nDistance: 9 40").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError {
+                line: 2,
+                columns: 15..18,
+                expected: "end of line after one distance per time".to_owned(),
+            }),
+            parse_input("Time: 7 15\nDistance: 9 40 200 1").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn it_rejects_empty_time_list() {
+        assert_eq!(
+            Err(ParseError {
+                line: 1,
+                columns: 5..5,
+                expected: "time".to_owned(),
+            }),
+            parse_input("Time:\nDistance:\n").map(|_| ())
+        );
+    }
+}
@@ -1,29 +1,20 @@
 use std::{cmp::Ordering, collections::HashMap};
 
+use aoc_macros::{aoc, FromInput};
 use itertools::Itertools;
 
+use crate::parser::{Cursor, FromInput, Lines, ParseError};
+
 enum Rule {
     Basic,
     Advanced,
 }
 
 impl Rule {
-    fn card(&self, card: char) -> Card {
-        match card {
-            '2' => Card::Two,
-            '3' => Card::Three,
-            '4' => Card::Four,
-            '5' => Card::Five,
-            '6' => Card::Six,
-            '7' => Card::Seven,
-            '8' => Card::Eight,
-            '9' => Card::Nine,
-            'T' => Card::Ten,
-            'J' => matches!(self, Self::Basic).then_some(Card::Jack).unwrap_or(Card::Joker),
-            'Q' => Card::Queen,
-            'K' => Card::King,
-            'A' => Card::Ace,
-            _ => unreachable!(),
+    fn card(&self, card: Card) -> Card {
+        match (self, card) {
+            (Self::Advanced, Card::Jack) => Card::Joker,
+            _ => card,
         }
     }
 
@@ -80,6 +71,38 @@ enum Card {
     Ace,
 }
 
+impl TryFrom<char> for Card {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '2' => Ok(Self::Two),
+            '3' => Ok(Self::Three),
+            '4' => Ok(Self::Four),
+            '5' => Ok(Self::Five),
+            '6' => Ok(Self::Six),
+            '7' => Ok(Self::Seven),
+            '8' => Ok(Self::Eight),
+            '9' => Ok(Self::Nine),
+            'T' => Ok(Self::Ten),
+            'J' => Ok(Self::Jack),
+            'Q' => Ok(Self::Queen),
+            'K' => Ok(Self::King),
+            'A' => Ok(Self::Ace),
+            _ => Err(()),
+        }
+    }
+}
+
+impl FromInput for Card {
+    fn read(cursor: &mut Cursor) -> Result<Self, ParseError> {
+        let start = cursor.clone();
+        let c = cursor.char()?;
+
+        Self::try_from(c).map_err(|_| start.error_at(&start.rest().trim_start()[..c.len_utf8()], "card"))
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(u8)]
 enum CardCombinaison {
@@ -111,87 +134,42 @@ impl CardCombinaison {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, FromInput)]
+#[input("{cards} {bid}")]
 struct Hand {
     cards: Vec<Card>,
-    card_combinaison: CardCombinaison,
     bid: usize,
 }
 
-fn get_input() -> &'static str {
-    include_str!("../../data/day07.txt")
+#[aoc(year = 2023, day = 7)]
+fn parse_input(input: &str) -> Result<Vec<Hand>, ParseError> {
+    Lines::new(input).map(Hand::from_line).collect()
 }
 
-fn parse_input(input: &str, rule: Rule) -> Vec<Hand> {
-    input
-        .lines()
-        .map(|s| {
-            let s = s.split_whitespace().take(2).collect::<Vec<_>>();
-
-            let cards = s[0].chars().map(|c| rule.card(c)).collect::<Vec<_>>();
+fn get_solution(hands: &[Hand], rule: Rule) -> usize {
+    hands
+        .iter()
+        .map(|hand| {
+            let cards = hand.cards.iter().map(|card| rule.card(*card)).collect::<Vec<_>>();
             let card_combinaison = rule.card_combinaison(&cards);
 
-            Hand {
-                cards,
-                card_combinaison,
-                bid: s[1].parse().unwrap(),
-            }
+            (card_combinaison, cards, hand.bid)
         })
-        .collect()
-}
-
-fn get_solution(hands: Vec<Hand>) -> usize {
-    hands
-        .iter()
-        .sorted_by(|a, b| match Ord::cmp(&a.card_combinaison, &b.card_combinaison) {
-            Ordering::Equal => Ord::cmp(&a.cards, &b.cards),
+        .sorted_by(|a, b| match Ord::cmp(&a.0, &b.0) {
+            Ordering::Equal => Ord::cmp(&a.1, &b.1),
             ordering => ordering,
         })
         .enumerate()
-        .map(|(i, hand)| (i + 1) * hand.bid)
+        .map(|(i, (_, _, bid))| (i + 1) * bid)
         .sum()
 }
 
-pub fn solve_part1() {
-    let input = get_input();
-    let hands = parse_input(input, Rule::Basic);
-    let solution = get_solution(hands);
-
-    println!("{solution}");
-}
-
-pub fn solve_part2() {
-    let input = get_input();
-    let hands = parse_input(input, Rule::Advanced);
-    let solution = get_solution(hands);
-
-    println!("{solution}");
+#[aoc(year = 2023, day = 7, part = 1)]
+fn get_solution_part1(hands: &[Hand]) -> usize {
+    get_solution(hands, Rule::Basic)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "\
-32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
-
-    #[test]
-    fn it_solves_example_part1() {
-        let hands = parse_input(INPUT, Rule::Basic);
-        let solution = get_solution(hands);
-
-        assert_eq!(6440, solution);
-    }
-
-    #[test]
-    fn it_solves_example_part2() {
-        let hands = parse_input(INPUT, Rule::Advanced);
-        let solution = get_solution(hands);
-
-        assert_eq!(5905, solution);
-    }
+#[aoc(year = 2023, day = 7, part = 2)]
+fn get_solution_part2(hands: &[Hand]) -> usize {
+    get_solution(hands, Rule::Advanced)
 }
@@ -0,0 +1,46 @@
+//! Checks the answers of every day against the recorded ones, one test being generated by the build script per day
+//! module, skipping the days whose input is absent or empty and failing on the parts whose answer is not recorded.
+//!
+//! Some parts are brute-forced and take minutes to run, they are listed in `src/slow.rs` to be ignored by default
+//! and run with `cargo test --release -- --ignored`.
+
+use std::fs;
+use std::path::Path;
+
+use advent_of_code_2023::answers::{Answers, Status, DEFAULT_ANSWERS_PATH};
+use advent_of_code_2023::{input, runner, Day, Part};
+
+fn verify(day: Day) {
+    let path = input::get_input_path(day, Path::new(input::DEFAULT_DATA_DIR));
+
+    // freshly scaffolded days have an empty input until it is fetched
+    if !fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+        eprintln!("skipping {day}: no input at '{}'", path.display());
+        return;
+    }
+
+    let answers = Answers::load(Path::new(DEFAULT_ANSWERS_PATH)).expect("answers file must be well-formed");
+    let records = runner::solve_day(day, &path, &[Part::One, Part::Two], 1).expect("input must be well-formed");
+    let failures = answers
+        .verify(&records)
+        .into_iter()
+        .filter(|verification| verification.status() != Status::Ok)
+        .map(|verification| verification.to_string())
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+}
+
+macro_rules! verify {
+    ($($(#[$attr:meta])* $name:ident: $year:literal / $day:literal),* $(,)?) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                verify(Day::from_number($year, $day).unwrap());
+            }
+        )*
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/answers.rs"));
@@ -0,0 +1,38 @@
+//! Solves every example input of `examples/{year}/day{nn}/`, one test being generated by the build script per file,
+//! and checks the answers expected by its header.
+
+use std::path::Path;
+
+use advent_of_code_2023::examples::{Example, DEFAULT_EXAMPLES_DIR};
+use advent_of_code_2023::Day;
+
+fn check(day: Day, path: &Path) {
+    let example = Example::load(&Path::new(DEFAULT_EXAMPLES_DIR).join(path)).expect("example must be well-formed");
+    let model = advent_of_code_2023::parse(day, &example.input).expect("example input must be well-formed");
+    let failures = example
+        .expected
+        .iter()
+        .filter_map(|(part, expected)| {
+            let answer = advent_of_code_2023::solve(day, *part, &model);
+
+            (answer.to_string() != *expected).then(|| format!("{day} part {part}: got {answer}, expected {expected}"))
+        })
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+}
+
+macro_rules! examples {
+    ($($name:ident: $year:literal / $day:literal / $path:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                let day = Day::from_number($year, $day).expect("examples must belong to an implemented day");
+
+                check(day, Path::new($path));
+            }
+        )*
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,49 @@
+//! Reads the puzzle page cached in `tests/fixtures`, laid out as a data directory, rendering it as the expected
+//! documents next to it and extracting examples the day solves.
+
+use std::fs;
+use std::path::Path;
+
+use advent_of_code_2023::puzzle::{self, Style};
+use advent_of_code_2023::Day;
+
+const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+const URL: &str = "https://adventofcode.com/2023/day/1";
+
+fn page() -> String {
+    fs::read_to_string(puzzle::get_page_path(2023, 1, Path::new(FIXTURES_DIR))).unwrap()
+}
+
+#[test]
+fn it_renders_pages_as_plain_text_and_markdown() {
+    let fixture = |extension| fs::read_to_string(Path::new(FIXTURES_DIR).join("2023").join(extension)).unwrap();
+
+    assert_eq!(fixture("day01.txt"), puzzle::render(&page(), URL, Style::Plain, 60));
+    assert_eq!(fixture("day01.md"), puzzle::render(&page(), URL, Style::Markdown, 60));
+}
+
+#[test]
+fn it_styles_pages_for_terminals() {
+    let text = puzzle::render(&page(), URL, Style::Terminal, 60);
+
+    assert!(text.starts_with("\x1b[1m--- Day 1: Sample Sum ---\x1b[0m\n"));
+    assert!(text.contains("produces \x1b[1m\x1b[36m112\x1b[0m\x1b[1m\x1b[0m."));
+    assert!(!text.contains("comment") && !text.contains("[Submit]") && !text.contains("[About]"));
+}
+
+#[test]
+fn it_extracts_examples_solved_by_the_day() {
+    let day = Day::from_number(2023, 1).unwrap();
+    let examples = puzzle::extract_examples(&page());
+
+    assert_eq!(2, examples.len());
+
+    for example in examples {
+        let model = advent_of_code_2023::parse(day, &example.input).unwrap();
+
+        for (part, expected) in &example.expected {
+            assert_eq!(*expected, advent_of_code_2023::solve(day, *part, &model).to_string());
+        }
+    }
+}
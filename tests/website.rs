@@ -0,0 +1,212 @@
+//! Talks to a local stand-in of the puzzle website, unlocking the first 3 days to the `secret` session, having solved
+//! the first part of day 1 only and expecting `42` as every answer.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use advent_of_code_2023::website::{Client, Error, Fetched, Verdict, USER_AGENT};
+use advent_of_code_2023::{Answer, Part};
+
+const YEAR: u16 = 2023;
+
+/// Request received by the stand-in server.
+#[derive(Debug, Clone)]
+struct Request {
+    path: String,
+    user_agent: Option<String>,
+    body: String,
+}
+
+fn respond(path: &str, cookie: Option<&str>, body: &str) -> (&'static str, String) {
+    let Some(path) = path.strip_prefix(&format!("/{YEAR}/day/")) else {
+        return ("404 Not Found", "404 Not Found".to_owned());
+    };
+    let (day, endpoint) = path.split_once('/').unwrap_or((path, ""));
+    let day = day.parse::<u8>().unwrap();
+
+    if cookie != Some("session=secret") {
+        return ("400 Bad Request", "Please log in to get your puzzle input.".to_owned());
+    }
+
+    if day > 3 {
+        return ("404 Not Found", "Not unlocked yet!".to_owned());
+    }
+
+    if endpoint == "input" {
+        return ("200 OK", format!("input of day {day}\n"));
+    }
+
+    if endpoint.is_empty() {
+        let mut page = format!("<main><article class=\"day-desc\"><h2>--- Day {day} ---</h2></article>");
+
+        if day == 1 {
+            page.push_str("<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2></article>");
+        }
+
+        return ("200 OK", page + "</main>");
+    }
+
+    let answer = body.split('&').find_map(|pair| pair.strip_prefix("answer=")).unwrap();
+    let article = match answer.parse::<i64>() {
+        Ok(0) => "You gave an answer too recently. You have 1m 5s left to wait.".to_owned(),
+        Ok(42) => "That's the right answer!".to_owned(),
+        Ok(n) if n > 42 => "That's not the right answer; your answer is too high.".to_owned(),
+        Ok(_) => "That's not the right answer; your answer is too low.".to_owned(),
+        Err(_) => "That's not the right answer.".to_owned(),
+    };
+
+    ("200 OK", format!("<main><article><p>{article}</p></article></main>"))
+}
+
+/// Spawns the stand-in server, returning its base URL and the requests it receives.
+fn serve() -> (String, Arc<Mutex<Vec<Request>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::default()));
+    let log = Arc::clone(&requests);
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut lines = Vec::default();
+
+            loop {
+                let mut line = String::new();
+
+                reader.read_line(&mut line).unwrap();
+
+                match line.trim_end() {
+                    "" => break,
+                    line => lines.push(line.to_owned()),
+                }
+            }
+
+            let path = lines[0].split(' ').nth(1).unwrap().to_owned();
+            let header = |name: &str| {
+                lines[1..].iter().find_map(|header| {
+                    let (key, value) = header.split_once(": ")?;
+
+                    key.eq_ignore_ascii_case(name).then(|| value.to_owned())
+                })
+            };
+            let mut body = vec![0; header("Content-Length").map_or(0, |len| len.parse().unwrap())];
+
+            reader.read_exact(&mut body).unwrap();
+
+            let body = String::from_utf8(body).unwrap();
+            let (status, response) = respond(&path, header("Cookie").as_deref(), &body);
+
+            log.lock().unwrap().push(Request {
+                path,
+                user_agent: header("User-Agent"),
+                body,
+            });
+
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                response.len()
+            )
+            .unwrap();
+        }
+    });
+
+    (base_url, requests)
+}
+
+#[test]
+fn it_downloads_inputs_once() {
+    let (base_url, requests) = serve();
+    let data_dir = tempfile::tempdir().unwrap();
+    let mut client = Client::new(&base_url, "secret", Duration::ZERO);
+
+    let Ok(Fetched::Downloaded(path)) = client.fetch_input(YEAR, 2, data_dir.path()) else {
+        panic!("day 2 must be downloaded");
+    };
+
+    assert_eq!("input of day 2\n", std::fs::read_to_string(&path).unwrap());
+    assert_eq!(
+        Some(Fetched::Cached(path)),
+        client.fetch_input(YEAR, 2, data_dir.path()).ok()
+    );
+
+    let requests = requests.lock().unwrap();
+
+    assert_eq!(1, requests.len());
+    assert_eq!(format!("/{YEAR}/day/2/input"), requests[0].path);
+    assert_eq!(Some(USER_AGENT), requests[0].user_agent.as_deref());
+}
+
+#[test]
+fn it_downloads_puzzle_pages_until_both_parts_are_revealed() {
+    let (base_url, requests) = serve();
+    let data_dir = tempfile::tempdir().unwrap();
+    let mut client = Client::new(&base_url, "secret", Duration::ZERO);
+    let mut fetch = |day| client.fetch_puzzle(YEAR, day, data_dir.path()).unwrap();
+
+    assert!(matches!(fetch(1), Fetched::Downloaded(_)));
+    assert!(matches!(fetch(1), Fetched::Cached(_)));
+    assert!(matches!(fetch(2), Fetched::Downloaded(_)));
+
+    let Fetched::Downloaded(path) = fetch(2) else {
+        panic!("the second part of day 2 is not revealed yet");
+    };
+
+    assert!(std::fs::read_to_string(path).unwrap().contains("--- Day 2 ---"));
+    assert_eq!(3, requests.lock().unwrap().len());
+    assert_eq!(format!("/{YEAR}/day/2"), requests.lock().unwrap()[2].path);
+}
+
+#[test]
+fn it_throttles_requests() {
+    let (base_url, requests) = serve();
+    let data_dir = tempfile::tempdir().unwrap();
+    let mut client = Client::new(&base_url, "secret", Duration::from_millis(200));
+    let start = Instant::now();
+
+    for day in 1..=3 {
+        assert!(client.fetch_input(YEAR, day, data_dir.path()).is_ok());
+    }
+
+    // the first request is sent right away, each following one waits for the interval
+    assert!(start.elapsed() >= Duration::from_millis(400));
+    assert_eq!(3, requests.lock().unwrap().len());
+}
+
+#[test]
+fn it_reports_expired_sessions_and_locked_days() {
+    let (base_url, _) = serve();
+    let data_dir = tempfile::tempdir().unwrap();
+
+    assert!(matches!(
+        Client::new(&base_url, "expired", Duration::ZERO).fetch_input(YEAR, 1, data_dir.path()),
+        Err(Error::Session)
+    ));
+    assert!(matches!(
+        Client::new(&base_url, "secret", Duration::ZERO).fetch_input(YEAR, 25, data_dir.path()),
+        Err(Error::Locked(YEAR, 25))
+    ));
+    assert_eq!(0, std::fs::read_dir(data_dir.path()).unwrap().count());
+}
+
+#[test]
+fn it_submits_answers() {
+    let (base_url, requests) = serve();
+    let mut client = Client::new(&base_url, "secret", Duration::ZERO);
+    let mut submit = |answer: Answer| client.submit_answer(YEAR, 1, Part::Two, &answer).unwrap();
+
+    assert_eq!(Verdict::TooHigh, submit(43.into()));
+    assert_eq!(Verdict::TooLow, submit(41.into()));
+    assert_eq!(Verdict::Wrong, submit("forty-two".into()));
+    assert_eq!(Verdict::Wait(Duration::from_secs(65)), submit(0.into()));
+    assert_eq!(Verdict::Correct, submit(42.into()));
+
+    let requests = requests.lock().unwrap();
+
+    assert_eq!(format!("/{YEAR}/day/1/answer"), requests[0].path);
+    assert_eq!("level=2&answer=43", requests[0].body);
+}